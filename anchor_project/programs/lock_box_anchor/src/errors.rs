@@ -19,4 +19,141 @@ pub enum LockBoxError {
 
     #[msg("Insufficient balance in vault for withdrawal")]
     InsufficientBalance,
+
+    #[msg("Unlock time must be in the future")]
+    InvalidUnlockTime,
+
+    #[msg("Unlock policy does not match the provided unlock time")]
+    InvalidUnlockPolicy,
+
+    #[msg("Unlock time not reached yet. Funds are still time-locked.")]
+    UnlockTimeNotReached,
+
+    #[msg("This LockBox is paused")]
+    LockBoxPaused,
+
+    #[msg("This action is not allowed in the LockBox's current status")]
+    InvalidStatusTransition,
+
+    #[msg("Penalty exceeds the maximum allowed basis points")]
+    InvalidPenaltyBps,
+
+    #[msg("Penalty decay duration cannot be negative")]
+    InvalidPenaltySchedule,
+
+    #[msg("Invalid program configuration")]
+    InvalidConfig,
+
+    #[msg("The program is paused by the admin")]
+    ProgramPaused,
+
+    #[msg("Target amount is outside the range allowed by the program config")]
+    TargetOutOfRange,
+
+    #[msg("Treasury account does not match the program config")]
+    InvalidTreasury,
+
+    #[msg("Crowdfund deadline must be in the future and use a target-only unlock policy")]
+    InvalidCrowdfundDeadline,
+
+    #[msg("The crowdfund deadline has passed")]
+    CrowdfundEnded,
+
+    #[msg("Crowdfund LockBoxes only accept contributions and cannot be emergency withdrawn")]
+    CrowdfundRestricted,
+
+    #[msg("This LockBox is not a crowdfund")]
+    NotCrowdfund,
+
+    #[msg("Refunds are only available once a crowdfund missed its target by the deadline")]
+    RefundNotAvailable,
+
+    #[msg("This instruction does not support the LockBox's asset (SOL or SPL token)")]
+    AssetMismatch,
+
+    #[msg("Amount is below the rent-exempt minimum of an empty vault")]
+    BelowRentExemptMinimum,
+
+    #[msg("Withdrawal must empty the vault or leave at least its rent-exempt minimum")]
+    VaultBelowRentExemptMinimum,
+
+    #[msg("The target can only be lowered after the cooldown or by paying the early-exit penalty")]
+    TargetCooldownActive,
+
+    #[msg("Signer is not the pending owner of this LockBox")]
+    InvalidPendingOwner,
+
+    #[msg("Withdrawals must be paid to the LockBox's beneficiary")]
+    InvalidBeneficiary,
+
+    #[msg("The current beneficiary must sign to change the beneficiary")]
+    BeneficiaryConsentRequired,
+
+    #[msg("Guardians must be unique, exclude the owner, and the threshold must be between 1 and their count")]
+    InvalidGuardians,
+
+    #[msg("Not enough guardians co-signed this action")]
+    GuardianApprovalRequired,
+
+    #[msg("Multisig needs 1 to 5 unique signers and a threshold between 1 and their count")]
+    InvalidMultisig,
+
+    #[msg("This LockBox is multisig-controlled; use a proposal")]
+    MultisigRequired,
+
+    #[msg("Signer is not part of this LockBox's multisig")]
+    NotMultisigSigner,
+
+    #[msg("This signer already approved the proposal")]
+    AlreadyApproved,
+
+    #[msg("The proposal does not have enough approvals yet")]
+    ThresholdNotMet,
+
+    #[msg(
+        "The heir must differ from the owner and the inactivity period must be at least the configured minimum inactivity period"
+    )]
+    InvalidInheritance,
+
+    #[msg("Signer is not the heir of this LockBox")]
+    NotHeir,
+
+    #[msg("The owner has not been inactive for the full inactivity period yet")]
+    OwnerStillActive,
+
+    #[msg("Vesting duration cannot be negative")]
+    InvalidVestingDuration,
+
+    #[msg("Withdrawal exceeds the amount vested so far")]
+    AmountNotVested,
+
+    #[msg("A withdrawal limit needs a window, and once unlocked it can only be tightened")]
+    InvalidWithdrawalLimit,
+
+    #[msg(
+        "Withdrawal exceeds the allowance of the current window; see the logs for when it resets"
+    )]
+    WithdrawalLimitExceeded,
+
+    #[msg("Milestones must ascend below 100% of the target, unlock up to 100%, and are not allowed for crowdfunds")]
+    InvalidMilestones,
+
+    #[msg("The automatic end of a pause must be in the future")]
+    InvalidPauseEnd,
+
+    #[msg("The crowdfund may still fail, so its contribution receipts are kept for refunds")]
+    ContributionStillRefundable,
+
+    #[msg("Lowering the target this far would unlock the LockBox, which costs the early-exit penalty (pay_penalty)")]
+    TargetDecreaseUnlocks,
+
+    #[msg("A guardian cannot become the owner of the LockBox it guards")]
+    GuardianCannotOwn,
+
+    #[msg(
+        "An unlocked LockBox with vesting or a withdrawal limit pays out through withdraw, not an emergency withdrawal"
+    )]
+    EmergencyExitRestricted,
+    #[msg("This contribution receipt belongs to an earlier LockBox at the same address")]
+    StaleContribution,
 }
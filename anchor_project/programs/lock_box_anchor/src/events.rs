@@ -0,0 +1,127 @@
+use crate::states::ProposalAction;
+use anchor_lang::prelude::*;
+
+// Emitted through `emit_cpi!` so indexers can read them from the inner
+// instructions even when the program logs are truncated.
+
+#[event]
+pub struct LockBoxCreated {
+    pub lockbox: Pubkey,
+    pub owner: Pubkey,
+    pub mint: Option<Pubkey>, // None for SOL
+    pub target_amount: u64,
+    pub unlock_at: Option<i64>,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct Deposited {
+    pub lockbox: Pubkey,
+    pub owner: Pubkey,
+    pub depositor: Pubkey, // owner, contributor, or the vault for synced lamports
+    pub amount: u64,
+    pub balance: u64, // balance after the deposit
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct Withdrawn {
+    pub lockbox: Pubkey,
+    pub owner: Pubkey,
+    pub recipient: Pubkey,
+    pub amount: u64,
+    pub balance: u64, // balance after the withdrawal
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct TargetReached {
+    pub lockbox: Pubkey,
+    pub owner: Pubkey,
+    pub balance: u64,
+    pub target_amount: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct EmergencyWithdrawn {
+    pub lockbox: Pubkey,
+    pub owner: Pubkey,
+    pub recipient: Pubkey,
+    pub amount: u64,  // paid to the recipient
+    pub penalty: u64, // paid to the treasury
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct LockBoxClosed {
+    pub lockbox: Pubkey,
+    pub owner: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct OwnershipTransferred {
+    pub lockbox: Pubkey,
+    pub owner: Pubkey, // the new owner
+    pub previous_owner: Pubkey,
+    pub inherited: bool, // claimed by the heir rather than accepted
+    pub timestamp: i64,
+}
+
+/// Which setting a `SettingsChanged` event reports; the new value is on the LockBox
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum LockBoxSetting {
+    PendingOwner,
+    Beneficiary,
+    Guardians,
+    Multisig,
+    Heir,
+    WithdrawalLimit,
+    Target,
+}
+
+#[event]
+pub struct SettingsChanged {
+    pub lockbox: Pubkey,
+    pub owner: Pubkey,
+    pub setting: LockBoxSetting,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct LockBoxPaused {
+    pub lockbox: Pubkey,
+    pub owner: Pubkey,
+    pub paused_until: Option<i64>,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct LockBoxResumed {
+    pub lockbox: Pubkey,
+    pub owner: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ProposalCreated {
+    pub lockbox: Pubkey,
+    pub owner: Pubkey,
+    pub proposal: Pubkey,
+    pub proposal_id: u64,
+    pub proposer: Pubkey, // counts as the first approval
+    pub action: ProposalAction,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ProposalApproved {
+    pub lockbox: Pubkey,
+    pub owner: Pubkey,
+    pub proposal: Pubkey,
+    pub proposal_id: u64,
+    pub approver: Pubkey,
+    pub approvals: u8, // approvals so far, including this one
+    pub timestamp: i64,
+}
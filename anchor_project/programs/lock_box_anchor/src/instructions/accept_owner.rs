@@ -0,0 +1,59 @@
+use crate::errors::LockBoxError;
+use crate::events::OwnershipTransferred;
+use crate::states::{Config, LockBox, CONFIG_SEED, LOCKBOX_SEED};
+use anchor_lang::prelude::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct AcceptOwner<'info> {
+    #[account(
+        mut,
+        seeds = [LOCKBOX_SEED, lockbox.creator.as_ref(), lockbox.id.to_le_bytes().as_ref()],
+        bump = lockbox.bump,
+        constraint = lockbox.pending_owner == Some(new_owner.key()) @ LockBoxError::InvalidPendingOwner,
+        constraint = !lockbox.guardians.contains(&new_owner.key()) @ LockBoxError::GuardianCannotOwn
+    )]
+    pub lockbox: Account<'info, LockBox>,
+
+    pub new_owner: Signer<'info>,
+
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump
+    )]
+    pub config: Account<'info, Config>,
+}
+
+pub fn accept_owner(ctx: Context<AcceptOwner>) -> Result<()> {
+    ctx.accounts.config.require_not_paused()?;
+
+    let clock = Clock::get()?;
+    ctx.accounts
+        .lockbox
+        .require_not_paused(clock.unix_timestamp)?;
+    ctx.accounts.lockbox.require_operational()?;
+
+    // The PDA stays derived from the creator, so the address survives the handover
+    let lockbox = &mut ctx.accounts.lockbox;
+    let previous_owner = lockbox.owner;
+    lockbox.owner = ctx.accounts.new_owner.key();
+    lockbox.pending_owner = None;
+    lockbox.touch(clock.unix_timestamp);
+
+    msg!(
+        "Ownership of LockBox #{} transferred from {} to {}",
+        lockbox.id,
+        previous_owner,
+        lockbox.owner
+    );
+
+    emit_cpi!(OwnershipTransferred {
+        lockbox: lockbox.key(),
+        owner: lockbox.owner,
+        previous_owner,
+        inherited: false,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,75 @@
+use crate::errors::LockBoxError;
+use crate::events::ProposalApproved;
+use crate::states::{
+    Config, LockBox, Proposal, ProposalAction, CONFIG_SEED, LOCKBOX_SEED, PROPOSAL_SEED,
+};
+use anchor_lang::prelude::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ApproveProposal<'info> {
+    #[account(
+        seeds = [LOCKBOX_SEED, lockbox.creator.as_ref(), lockbox.id.to_le_bytes().as_ref()],
+        bump = lockbox.bump,
+        constraint = lockbox.signers.contains(&signer.key()) @ LockBoxError::NotMultisigSigner
+    )]
+    pub lockbox: Account<'info, LockBox>,
+
+    #[account(
+        mut,
+        seeds = [PROPOSAL_SEED, lockbox.key().as_ref(), proposal.id.to_le_bytes().as_ref()],
+        bump = proposal.bump,
+        has_one = lockbox
+    )]
+    pub proposal: Account<'info, Proposal>,
+
+    pub signer: Signer<'info>,
+
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump
+    )]
+    pub config: Account<'info, Config>,
+}
+
+pub fn approve_proposal(ctx: Context<ApproveProposal>) -> Result<()> {
+    ctx.accounts.config.require_not_paused()?;
+
+    let clock = Clock::get()?;
+    if ctx.accounts.proposal.action == ProposalAction::Resume {
+        ctx.accounts.lockbox.require_paused()?;
+    } else {
+        ctx.accounts
+            .lockbox
+            .require_not_paused(clock.unix_timestamp)?;
+        ctx.accounts.lockbox.require_operational()?;
+    }
+
+    let signer = ctx.accounts.signer.key();
+    let proposal = &mut ctx.accounts.proposal;
+    require!(
+        !proposal.approvals.contains(&signer),
+        LockBoxError::AlreadyApproved
+    );
+    proposal.approvals.push(signer);
+
+    msg!(
+        "Proposal #{} approved by {} ({} of {} approvals)",
+        proposal.id,
+        signer,
+        proposal.approvals.len(),
+        ctx.accounts.lockbox.signer_threshold
+    );
+
+    emit_cpi!(ProposalApproved {
+        lockbox: ctx.accounts.lockbox.key(),
+        owner: ctx.accounts.lockbox.owner,
+        proposal: proposal.key(),
+        proposal_id: proposal.id,
+        approver: signer,
+        approvals: proposal.approvals.len() as u8,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,78 @@
+use crate::errors::LockBoxError;
+use crate::events::OwnershipTransferred;
+use crate::states::{Config, LockBox, LockBoxStatus, CONFIG_SEED, LOCKBOX_SEED};
+use anchor_lang::prelude::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ClaimInheritance<'info> {
+    #[account(
+        mut,
+        seeds = [LOCKBOX_SEED, lockbox.creator.as_ref(), lockbox.id.to_le_bytes().as_ref()],
+        bump = lockbox.bump,
+        constraint = lockbox.heir == Some(heir.key()) @ LockBoxError::NotHeir,
+        constraint = !lockbox.is_multisig() @ LockBoxError::MultisigRequired
+    )]
+    pub lockbox: Account<'info, LockBox>,
+
+    pub heir: Signer<'info>,
+
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump
+    )]
+    pub config: Account<'info, Config>,
+}
+
+pub fn claim_inheritance(ctx: Context<ClaimInheritance>) -> Result<()> {
+    ctx.accounts.config.require_not_paused()?;
+
+    // A pause doesn't block the heir: it moves no funds, and the owner may have paused before disappearing
+    require!(
+        matches!(
+            ctx.accounts.lockbox.status,
+            LockBoxStatus::Active | LockBoxStatus::Unlocked | LockBoxStatus::Paused
+        ),
+        LockBoxError::VaultInactive
+    );
+
+    let clock = Clock::get()?;
+    require!(
+        ctx.accounts
+            .lockbox
+            .inheritance_claimable(clock.unix_timestamp),
+        LockBoxError::OwnerStillActive
+    );
+
+    // The heir takes over the LockBox and with it the vault; the PDA stays derived from the creator.
+    // The lock terms carry over, but the guardians and beneficiary were the previous owner's
+    // choices and would otherwise keep the heir from the funds, so they are cleared.
+    // Clearing the guardians also lets a guardian inherit without guarding themselves.
+    let lockbox = &mut ctx.accounts.lockbox;
+    let previous_owner = lockbox.owner;
+    let inactive_since = lockbox.last_activity_at;
+    lockbox.owner = ctx.accounts.heir.key();
+    lockbox.pending_owner = None;
+    lockbox.beneficiary = None;
+    lockbox.set_guardians(Vec::new(), 0)?;
+    lockbox.set_heir(None, 0, 0)?;
+    lockbox.touch(clock.unix_timestamp);
+
+    msg!(
+        "LockBox #{} inherited by {} after {} was inactive since {}",
+        lockbox.id,
+        lockbox.owner,
+        previous_owner,
+        inactive_since
+    );
+
+    emit_cpi!(OwnershipTransferred {
+        lockbox: lockbox.key(),
+        owner: lockbox.owner,
+        previous_owner,
+        inherited: true,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,111 @@
+use crate::errors::LockBoxError;
+use crate::events::Withdrawn;
+use crate::states::{
+    Config, Contribution, LockBox, CONFIG_SEED, CONTRIBUTION_SEED, LOCKBOX_SEED, VAULT_SEED,
+};
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ClaimRefund<'info> {
+    #[account(
+        mut,
+        seeds = [LOCKBOX_SEED, lockbox.creator.as_ref(), lockbox.id.to_le_bytes().as_ref()],
+        bump = lockbox.bump,
+        constraint = !lockbox.is_token() @ LockBoxError::AssetMismatch
+    )]
+    pub lockbox: Account<'info, LockBox>,
+
+    #[account(mut)]
+    pub contributor: Signer<'info>,
+
+    // The receipt is closed once refunded, so each contribution is paid back only once
+    #[account(
+        mut,
+        seeds = [CONTRIBUTION_SEED, lockbox.key().as_ref(), contributor.key().as_ref()],
+        bump = contribution.bump,
+        has_one = lockbox,
+        has_one = contributor @ LockBoxError::Unauthorized,
+        constraint = contribution.lockbox_nonce == lockbox.nonce @ LockBoxError::StaleContribution,
+        close = contributor
+    )]
+    pub contribution: Account<'info, Contribution>,
+
+    /// CHECK: This is the PDA that holds the SOL
+    #[account(
+        mut,
+        seeds = [VAULT_SEED, lockbox.key().as_ref()],
+        bump
+    )]
+    pub vault: AccountInfo<'info>,
+
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump
+    )]
+    pub config: Account<'info, Config>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn claim_refund(ctx: Context<ClaimRefund>) -> Result<()> {
+    ctx.accounts.config.require_not_paused()?;
+
+    let clock = Clock::get()?;
+    ctx.accounts
+        .lockbox
+        .require_not_paused(clock.unix_timestamp)?;
+
+    let lockbox = &ctx.accounts.lockbox;
+
+    require!(lockbox.is_crowdfund(), LockBoxError::NotCrowdfund);
+    require!(
+        lockbox.crowdfund_failed(clock.unix_timestamp),
+        LockBoxError::RefundNotAvailable
+    );
+
+    let refund_amount = ctx.accounts.contribution.total_amount;
+    require!(
+        lockbox.current_balance >= refund_amount,
+        LockBoxError::InsufficientBalance
+    );
+
+    // Transfer the contribution back from vault to contributor using CPI with signer seeds
+    let lockbox_key = lockbox.key();
+    let vault_seeds = &[VAULT_SEED, lockbox_key.as_ref(), &[ctx.bumps.vault]];
+    let signer_seeds = &[&vault_seeds[..]];
+
+    let cpi_context = CpiContext::new_with_signer(
+        ctx.accounts.system_program.to_account_info(),
+        Transfer {
+            from: ctx.accounts.vault.to_account_info(),
+            to: ctx.accounts.contributor.to_account_info(),
+        },
+        signer_seeds,
+    );
+
+    transfer(cpi_context, refund_amount)?;
+
+    // Update lockbox balance
+    let lockbox = &mut ctx.accounts.lockbox;
+    lockbox.current_balance = lockbox.current_balance.checked_sub(refund_amount).unwrap();
+
+    msg!(
+        "Refunded {} lamports to {}. Remaining balance: {} lamports",
+        refund_amount,
+        ctx.accounts.contributor.key(),
+        lockbox.current_balance
+    );
+
+    emit_cpi!(Withdrawn {
+        lockbox: lockbox.key(),
+        owner: lockbox.owner,
+        recipient: ctx.accounts.contributor.key(),
+        amount: refund_amount,
+        balance: lockbox.current_balance,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,57 @@
+use crate::errors::LockBoxError;
+use crate::states::{Config, Contribution, LockBox, LockBoxStatus, CONFIG_SEED, CONTRIBUTION_SEED};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct CloseContribution<'info> {
+    /// CHECK: The LockBox the receipt belongs to. It may already be closed, so it is only
+    /// deserialized in the handler while it still exists.
+    #[account(address = contribution.lockbox)]
+    pub lockbox: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub contributor: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [CONTRIBUTION_SEED, lockbox.key().as_ref(), contributor.key().as_ref()],
+        bump = contribution.bump,
+        has_one = contributor @ LockBoxError::Unauthorized,
+        close = contributor
+    )]
+    pub contribution: Account<'info, Contribution>,
+
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump
+    )]
+    pub config: Account<'info, Config>,
+}
+
+pub fn close_contribution(ctx: Context<CloseContribution>) -> Result<()> {
+    ctx.accounts.config.require_not_paused()?;
+
+    // A closed LockBox is handed back to the system program
+    let lockbox_info = &ctx.accounts.lockbox;
+    if lockbox_info.owner == &crate::ID {
+        let lockbox = LockBox::try_deserialize(&mut &lockbox_info.try_borrow_data()?[..])?;
+
+        // The receipt is what a running crowdfund refunds against if it fails, unless it was
+        // left over from an earlier LockBox at the same address
+        require!(
+            lockbox.nonce != ctx.accounts.contribution.lockbox_nonce
+                || !lockbox.is_crowdfund()
+                || lockbox.status != LockBoxStatus::Active,
+            LockBoxError::ContributionStillRefundable
+        );
+    }
+
+    msg!(
+        "Contribution receipt of {} closed after {} contributions totalling {} lamports",
+        ctx.accounts.contributor.key(),
+        ctx.accounts.contribution.count,
+        ctx.accounts.contribution.total_amount
+    );
+
+    Ok(())
+}
@@ -1,14 +1,18 @@
 use crate::errors::LockBoxError;
-use crate::states::{LockBox, LOCKBOX_SEED, VAULT_SEED};
+use crate::events::LockBoxClosed;
+use crate::states::{Config, LockBox, LockBoxStatus, CONFIG_SEED, LOCKBOX_SEED, VAULT_SEED};
 use anchor_lang::prelude::*;
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct CloseLockBox<'info> {
     #[account(
         mut,
-        seeds = [LOCKBOX_SEED, owner.key().as_ref()],
+        seeds = [LOCKBOX_SEED, lockbox.creator.as_ref(), lockbox.id.to_le_bytes().as_ref()],
         bump = lockbox.bump,
         has_one = owner @ LockBoxError::Unauthorized,
+        constraint = !lockbox.is_token() @ LockBoxError::AssetMismatch,
+        constraint = !lockbox.is_multisig() @ LockBoxError::MultisigRequired,
         close = owner
     )]
     pub lockbox: Account<'info, LockBox>,
@@ -24,16 +28,37 @@ pub struct CloseLockBox<'info> {
     )]
     pub vault: AccountInfo<'info>,
 
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump
+    )]
+    pub config: Account<'info, Config>,
+
     pub system_program: Program<'info, System>,
 }
 
 pub fn close_lockbox(ctx: Context<CloseLockBox>) -> Result<()> {
+    ctx.accounts.config.require_not_paused()?;
+
+    let clock = Clock::get()?;
+    ctx.accounts
+        .lockbox
+        .require_not_paused(clock.unix_timestamp)?;
+
     let vault_balance = ctx.accounts.vault.lamports();
 
     // Check if there are any funds left in the vault
     require!(vault_balance == 0, LockBoxError::InsufficientBalance);
 
+    ctx.accounts.lockbox.transition_to(LockBoxStatus::Closed)?;
+
     msg!("LockBox closed successfully. Rent lamports returned to owner.");
 
+    emit_cpi!(LockBoxClosed {
+        lockbox: ctx.accounts.lockbox.key(),
+        owner: ctx.accounts.owner.key(),
+        timestamp: clock.unix_timestamp,
+    });
+
     Ok(())
 }
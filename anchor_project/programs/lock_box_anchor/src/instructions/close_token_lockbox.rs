@@ -0,0 +1,106 @@
+use crate::errors::LockBoxError;
+use crate::events::LockBoxClosed;
+use crate::states::{Config, LockBox, LockBoxStatus, CONFIG_SEED, LOCKBOX_SEED, VAULT_SEED};
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::spl_token_2022::extension::transfer_fee::TransferFeeConfig;
+use anchor_spl::token_interface::{
+    close_account, get_mint_extension_data, harvest_withheld_tokens_to_mint, CloseAccount,
+    HarvestWithheldTokensToMint, Mint, TokenAccount, TokenInterface,
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct CloseTokenLockBox<'info> {
+    #[account(
+        mut,
+        seeds = [LOCKBOX_SEED, lockbox.creator.as_ref(), lockbox.id.to_le_bytes().as_ref()],
+        bump = lockbox.bump,
+        has_one = owner @ LockBoxError::Unauthorized,
+        constraint = lockbox.mint == Some(mint.key()) @ LockBoxError::AssetMismatch,
+        close = owner
+    )]
+    pub lockbox: Account<'info, LockBox>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    // Writable so withheld transfer fees can be harvested into it before closing
+    #[account(
+        mut,
+        mint::token_program = token_program
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        seeds = [VAULT_SEED, lockbox.key().as_ref()],
+        bump
+    )]
+    pub vault: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump
+    )]
+    pub config: Account<'info, Config>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+pub fn close_token_lockbox(ctx: Context<CloseTokenLockBox>) -> Result<()> {
+    ctx.accounts.config.require_not_paused()?;
+
+    let clock = Clock::get()?;
+    ctx.accounts
+        .lockbox
+        .require_not_paused(clock.unix_timestamp)?;
+
+    // Check if there are any tokens left in the vault
+    require!(
+        ctx.accounts.vault.amount == 0,
+        LockBoxError::InsufficientBalance
+    );
+
+    ctx.accounts.lockbox.transition_to(LockBoxStatus::Closed)?;
+
+    // Token-2022 refuses to close an account holding withheld transfer fees, so sweep them to the mint
+    let mint_info = ctx.accounts.mint.to_account_info();
+    if get_mint_extension_data::<TransferFeeConfig>(&mint_info).is_ok() {
+        let cpi_context = CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            HarvestWithheldTokensToMint {
+                token_program_id: ctx.accounts.token_program.to_account_info(),
+                mint: mint_info,
+            },
+        );
+
+        harvest_withheld_tokens_to_mint(cpi_context, vec![ctx.accounts.vault.to_account_info()])?;
+    }
+
+    // Close the empty token vault, signed by the vault PDA
+    let lockbox_key = ctx.accounts.lockbox.key();
+    let vault_seeds = &[VAULT_SEED, lockbox_key.as_ref(), &[ctx.bumps.vault]];
+    let signer_seeds = &[&vault_seeds[..]];
+
+    let cpi_context = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        CloseAccount {
+            account: ctx.accounts.vault.to_account_info(),
+            destination: ctx.accounts.owner.to_account_info(),
+            authority: ctx.accounts.vault.to_account_info(),
+        },
+        signer_seeds,
+    );
+
+    close_account(cpi_context)?;
+
+    msg!("Token LockBox closed successfully. Rent lamports returned to owner.");
+
+    emit_cpi!(LockBoxClosed {
+        lockbox: ctx.accounts.lockbox.key(),
+        owner: ctx.accounts.owner.key(),
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,145 @@
+use crate::errors::LockBoxError;
+use crate::events::{Deposited, TargetReached};
+use crate::states::{
+    vault_rent_minimum, Config, Contribution, LockBox, CONFIG_SEED, CONTRIBUTION_SEED,
+    LOCKBOX_SEED, VAULT_SEED,
+};
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct Contribute<'info> {
+    // Any LockBox can receive contributions, so the seeds come from the account itself
+    #[account(
+        mut,
+        seeds = [LOCKBOX_SEED, lockbox.creator.as_ref(), lockbox.id.to_le_bytes().as_ref()],
+        bump = lockbox.bump,
+        constraint = !lockbox.is_token() @ LockBoxError::AssetMismatch
+    )]
+    pub lockbox: Account<'info, LockBox>,
+
+    #[account(mut)]
+    pub contributor: Signer<'info>,
+
+    // Per-contributor receipt, created on the first contribution
+    #[account(
+        init_if_needed,
+        payer = contributor,
+        space = Contribution::LEN,
+        seeds = [CONTRIBUTION_SEED, lockbox.key().as_ref(), contributor.key().as_ref()],
+        bump
+    )]
+    pub contribution: Account<'info, Contribution>,
+
+    /// CHECK: This is the PDA that will hold the SOL
+    #[account(
+        mut,
+        seeds = [VAULT_SEED, lockbox.key().as_ref()],
+        bump
+    )]
+    pub vault: AccountInfo<'info>,
+
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump
+    )]
+    pub config: Account<'info, Config>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn contribute(ctx: Context<Contribute>, amount: u64) -> Result<()> {
+    require!(amount > 0, LockBoxError::InvalidDepositAmount);
+    ctx.accounts.config.require_not_paused()?;
+
+    let clock = Clock::get()?;
+    ctx.accounts
+        .lockbox
+        .require_not_paused(clock.unix_timestamp)?;
+    ctx.accounts.lockbox.require_operational()?;
+
+    if let Some(deadline) = ctx.accounts.lockbox.crowdfund_deadline {
+        require!(
+            clock.unix_timestamp < deadline,
+            LockBoxError::CrowdfundEnded
+        );
+    }
+
+    // A receipt left over from an earlier LockBox at this address can't be refunded
+    // from this one, so it starts over
+    let lockbox_nonce = ctx.accounts.lockbox.nonce;
+    let contribution = &mut ctx.accounts.contribution;
+    if contribution.count > 0 && contribution.lockbox_nonce != lockbox_nonce {
+        contribution.total_amount = 0;
+        contribution.count = 0;
+    }
+
+    // Each receipt holds at least the rent-exempt minimum, so refunding any set of
+    // contributions leaves the vault either empty or still rent-exempt
+    if ctx.accounts.contribution.count == 0 {
+        require!(
+            amount >= vault_rent_minimum()?,
+            LockBoxError::BelowRentExemptMinimum
+        );
+    }
+
+    // Transfer SOL from contributor to vault PDA
+    let cpi_context = CpiContext::new(
+        ctx.accounts.system_program.to_account_info(),
+        Transfer {
+            from: ctx.accounts.contributor.to_account_info(),
+            to: ctx.accounts.vault.to_account_info(),
+        },
+    );
+
+    transfer(cpi_context, amount)?;
+
+    // Update lockbox balance; withdrawals stay with the owner
+    let lockbox = &mut ctx.accounts.lockbox;
+    let target_was_reached = lockbox.has_reached_target();
+    lockbox.current_balance = lockbox.current_balance.checked_add(amount).unwrap();
+
+    msg!(
+        "{} contributed {} lamports. Current balance: {} / {} lamports",
+        ctx.accounts.contributor.key(),
+        amount,
+        lockbox.current_balance,
+        lockbox.target_amount
+    );
+
+    lockbox.check_target_reached(clock.unix_timestamp)?;
+
+    emit_cpi!(Deposited {
+        lockbox: lockbox.key(),
+        owner: lockbox.owner,
+        depositor: ctx.accounts.contributor.key(),
+        amount,
+        balance: lockbox.current_balance,
+        timestamp: clock.unix_timestamp,
+    });
+    if !target_was_reached && lockbox.has_reached_target() {
+        emit_cpi!(TargetReached {
+            lockbox: lockbox.key(),
+            owner: lockbox.owner,
+            balance: lockbox.current_balance,
+            target_amount: lockbox.target_amount,
+            timestamp: clock.unix_timestamp,
+        });
+    }
+
+    // Record the contribution on the contributor's receipt
+    let contribution = &mut ctx.accounts.contribution;
+    if contribution.count == 0 {
+        contribution.lockbox = lockbox.key();
+        contribution.lockbox_nonce = lockbox.nonce;
+        contribution.contributor = ctx.accounts.contributor.key();
+        contribution.first_contribution_at = clock.unix_timestamp;
+        contribution.bump = ctx.bumps.contribution;
+    }
+    contribution.total_amount = contribution.total_amount.checked_add(amount).unwrap();
+    contribution.last_contribution_at = clock.unix_timestamp;
+    contribution.count = contribution.count.checked_add(1).unwrap();
+
+    Ok(())
+}
@@ -0,0 +1,95 @@
+use crate::errors::LockBoxError;
+use crate::events::ProposalCreated;
+use crate::states::{
+    Config, LockBox, Proposal, ProposalAction, CONFIG_SEED, LOCKBOX_SEED, PROPOSAL_SEED,
+};
+use anchor_lang::prelude::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct CreateProposal<'info> {
+    #[account(
+        mut,
+        seeds = [LOCKBOX_SEED, lockbox.creator.as_ref(), lockbox.id.to_le_bytes().as_ref()],
+        bump = lockbox.bump,
+        constraint = lockbox.signers.contains(&proposer.key()) @ LockBoxError::NotMultisigSigner
+    )]
+    pub lockbox: Account<'info, LockBox>,
+
+    #[account(
+        init,
+        payer = proposer,
+        space = Proposal::LEN,
+        seeds = [
+            PROPOSAL_SEED,
+            lockbox.key().as_ref(),
+            lockbox.proposal_count.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub proposal: Account<'info, Proposal>,
+
+    #[account(mut)]
+    pub proposer: Signer<'info>,
+
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump
+    )]
+    pub config: Account<'info, Config>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn create_proposal(ctx: Context<CreateProposal>, action: ProposalAction) -> Result<()> {
+    ctx.accounts.config.require_not_paused()?;
+
+    let clock = Clock::get()?;
+    if action == ProposalAction::Resume {
+        ctx.accounts.lockbox.require_paused()?;
+        // Only the guardians resume a guarded LockBox, through resume_lockbox
+        require!(
+            ctx.accounts.lockbox.guardians.is_empty(),
+            LockBoxError::GuardianApprovalRequired
+        );
+    } else {
+        ctx.accounts
+            .lockbox
+            .require_not_paused(clock.unix_timestamp)?;
+        ctx.accounts.lockbox.require_operational()?;
+    }
+
+    let lockbox = &mut ctx.accounts.lockbox;
+    let proposal = &mut ctx.accounts.proposal;
+
+    // The proposer's approval is implied
+    proposal.lockbox = lockbox.key();
+    proposal.id = lockbox.proposal_count;
+    proposal.proposer = ctx.accounts.proposer.key();
+    proposal.action = action;
+    proposal.approvals = vec![ctx.accounts.proposer.key()];
+    proposal.created_at = clock.unix_timestamp;
+    proposal.bump = ctx.bumps.proposal;
+
+    lockbox.proposal_count = lockbox.proposal_count.checked_add(1).unwrap();
+
+    msg!(
+        "Proposal #{} on LockBox #{} created: {:?} (1 of {} approvals)",
+        proposal.id,
+        lockbox.id,
+        proposal.action,
+        lockbox.signer_threshold
+    );
+
+    emit_cpi!(ProposalCreated {
+        lockbox: lockbox.key(),
+        owner: lockbox.owner,
+        proposal: proposal.key(),
+        proposal_id: proposal.id,
+        proposer: proposal.proposer,
+        action: proposal.action,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
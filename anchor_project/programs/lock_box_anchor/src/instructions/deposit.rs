@@ -1,15 +1,18 @@
 use crate::errors::LockBoxError;
-use crate::states::{LockBox, LOCKBOX_SEED, VAULT_SEED};
+use crate::events::{Deposited, TargetReached};
+use crate::states::{vault_rent_minimum, Config, LockBox, CONFIG_SEED, LOCKBOX_SEED, VAULT_SEED};
 use anchor_lang::prelude::*;
 use anchor_lang::system_program::{transfer, Transfer};
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct Deposit<'info> {
     #[account(
         mut,
-        seeds = [LOCKBOX_SEED, owner.key().as_ref()],
+        seeds = [LOCKBOX_SEED, lockbox.creator.as_ref(), lockbox.id.to_le_bytes().as_ref()],
         bump = lockbox.bump,
-        has_one = owner @ LockBoxError::Unauthorized
+        has_one = owner @ LockBoxError::Unauthorized,
+        constraint = !lockbox.is_token() @ LockBoxError::AssetMismatch
     )]
     pub lockbox: Account<'info, LockBox>,
 
@@ -24,11 +27,36 @@ pub struct Deposit<'info> {
     )]
     pub vault: AccountInfo<'info>,
 
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump
+    )]
+    pub config: Account<'info, Config>,
+
     pub system_program: Program<'info, System>,
 }
 
 pub fn deposit(ctx: Context<Deposit>, amount: u64) -> Result<()> {
     require!(amount > 0, LockBoxError::InvalidDepositAmount);
+    ctx.accounts.config.require_not_paused()?;
+
+    let clock = Clock::get()?;
+    ctx.accounts
+        .lockbox
+        .require_not_paused(clock.unix_timestamp)?;
+    ctx.accounts.lockbox.require_operational()?;
+
+    // Crowdfund money must go through `contribute` so it can be refunded
+    require!(
+        !ctx.accounts.lockbox.is_crowdfund(),
+        LockBoxError::CrowdfundRestricted
+    );
+
+    // An empty vault only comes into existence with at least its rent-exempt minimum
+    require!(
+        ctx.accounts.vault.lamports().saturating_add(amount) >= vault_rent_minimum()?,
+        LockBoxError::BelowRentExemptMinimum
+    );
 
     // Transfer SOL from owner to vault PDA
     let cpi_context = CpiContext::new(
@@ -43,6 +71,7 @@ pub fn deposit(ctx: Context<Deposit>, amount: u64) -> Result<()> {
 
     // Update lockbox balance
     let lockbox = &mut ctx.accounts.lockbox;
+    let target_was_reached = lockbox.has_reached_target();
     lockbox.current_balance = lockbox.current_balance.checked_add(amount).unwrap();
 
     msg!(
@@ -52,10 +81,26 @@ pub fn deposit(ctx: Context<Deposit>, amount: u64) -> Result<()> {
         lockbox.target_amount
     );
 
-    // Check if target has been reached and set has_reached_target to true
-    if lockbox.current_balance >= lockbox.target_amount {
-        lockbox.has_reached_target = true;
-        msg!("🎉 Target reached! You can now withdraw your funds.");
+    // Check if target has been reached and unlock the LockBox if the policy allows it
+    lockbox.touch(clock.unix_timestamp);
+    lockbox.check_target_reached(clock.unix_timestamp)?;
+
+    emit_cpi!(Deposited {
+        lockbox: lockbox.key(),
+        owner: lockbox.owner,
+        depositor: ctx.accounts.owner.key(),
+        amount,
+        balance: lockbox.current_balance,
+        timestamp: clock.unix_timestamp,
+    });
+    if !target_was_reached && lockbox.has_reached_target() {
+        emit_cpi!(TargetReached {
+            lockbox: lockbox.key(),
+            owner: lockbox.owner,
+            balance: lockbox.current_balance,
+            target_amount: lockbox.target_amount,
+            timestamp: clock.unix_timestamp,
+        });
     }
 
     Ok(())
@@ -0,0 +1,129 @@
+use crate::errors::LockBoxError;
+use crate::events::{Deposited, TargetReached};
+use crate::states::{Config, LockBox, CONFIG_SEED, LOCKBOX_SEED, VAULT_SEED};
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::spl_token_2022::extension::interest_bearing_mint::InterestBearingConfig;
+use anchor_spl::token_interface::{
+    get_mint_extension_data, transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked,
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct DepositToken<'info> {
+    #[account(
+        mut,
+        seeds = [LOCKBOX_SEED, lockbox.creator.as_ref(), lockbox.id.to_le_bytes().as_ref()],
+        bump = lockbox.bump,
+        has_one = owner @ LockBoxError::Unauthorized,
+        constraint = lockbox.mint == Some(mint.key()) @ LockBoxError::AssetMismatch
+    )]
+    pub lockbox: Account<'info, LockBox>,
+
+    pub owner: Signer<'info>,
+
+    #[account(mint::token_program = token_program)]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = owner,
+        token::token_program = token_program
+    )]
+    pub owner_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        seeds = [VAULT_SEED, lockbox.key().as_ref()],
+        bump
+    )]
+    pub vault: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump
+    )]
+    pub config: Account<'info, Config>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+pub fn deposit_token(ctx: Context<DepositToken>, amount: u64) -> Result<()> {
+    require!(amount > 0, LockBoxError::InvalidDepositAmount);
+    ctx.accounts.config.require_not_paused()?;
+
+    let clock = Clock::get()?;
+    ctx.accounts
+        .lockbox
+        .require_not_paused(clock.unix_timestamp)?;
+    ctx.accounts.lockbox.require_operational()?;
+
+    // Transfer-fee mints deliver less than `amount`, so credit what the vault actually received
+    let vault_before = ctx.accounts.vault.amount;
+
+    // Transfer tokens from the owner's token account to the vault
+    let cpi_context = CpiContext::new(
+        ctx.accounts.token_program.to_account_info(),
+        TransferChecked {
+            from: ctx.accounts.owner_token_account.to_account_info(),
+            mint: ctx.accounts.mint.to_account_info(),
+            to: ctx.accounts.vault.to_account_info(),
+            authority: ctx.accounts.owner.to_account_info(),
+        },
+    );
+
+    transfer_checked(cpi_context, amount, ctx.accounts.mint.decimals)?;
+
+    ctx.accounts.vault.reload()?;
+    let received = ctx.accounts.vault.amount - vault_before;
+
+    // Update lockbox balance
+    let lockbox = &mut ctx.accounts.lockbox;
+    let target_was_reached = lockbox.has_reached_target();
+    lockbox.current_balance = lockbox.current_balance.checked_add(received).unwrap();
+
+    msg!(
+        "Deposited {} tokens ({} received). Current balance: {} / {} tokens",
+        amount,
+        received,
+        lockbox.current_balance,
+        lockbox.target_amount
+    );
+
+    // Interest-bearing mints display a growing UI amount for the same raw balance
+    let mint_info = ctx.accounts.mint.to_account_info();
+    if let Ok(interest) = get_mint_extension_data::<InterestBearingConfig>(&mint_info) {
+        let decimals = ctx.accounts.mint.decimals;
+        if let (Some(balance), Some(target)) = (
+            interest.amount_to_ui_amount(lockbox.current_balance, decimals, clock.unix_timestamp),
+            interest.amount_to_ui_amount(lockbox.target_amount, decimals, clock.unix_timestamp),
+        ) {
+            msg!("UI amount progress: {} / {}", balance, target);
+        }
+    }
+
+    lockbox.touch(clock.unix_timestamp);
+
+    // Check if target has been reached and unlock the LockBox if the policy allows it
+    lockbox.check_target_reached(clock.unix_timestamp)?;
+
+    emit_cpi!(Deposited {
+        lockbox: lockbox.key(),
+        owner: lockbox.owner,
+        depositor: ctx.accounts.owner.key(),
+        amount: received,
+        balance: lockbox.current_balance,
+        timestamp: clock.unix_timestamp,
+    });
+    if !target_was_reached && lockbox.has_reached_target() {
+        emit_cpi!(TargetReached {
+            lockbox: lockbox.key(),
+            owner: lockbox.owner,
+            balance: lockbox.current_balance,
+            target_amount: lockbox.target_amount,
+            timestamp: clock.unix_timestamp,
+        });
+    }
+
+    Ok(())
+}
@@ -1,22 +1,37 @@
 use crate::errors::LockBoxError;
-use crate::states::{LockBox, LOCKBOX_SEED, VAULT_SEED};
+use crate::events::{EmergencyWithdrawn, LockBoxClosed};
+use crate::states::{Config, LockBox, LockBoxStatus, CONFIG_SEED, LOCKBOX_SEED, VAULT_SEED};
 use anchor_lang::prelude::*;
 use anchor_lang::system_program::{transfer, Transfer};
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct EmergencyWithdraw<'info> {
     #[account(
         mut,
-        seeds = [LOCKBOX_SEED, owner.key().as_ref()],
+        seeds = [LOCKBOX_SEED, lockbox.creator.as_ref(), lockbox.id.to_le_bytes().as_ref()],
         bump = lockbox.bump,
         has_one = owner @ LockBoxError::Unauthorized,
-        close = owner
+        constraint = !lockbox.is_token() @ LockBoxError::AssetMismatch,
+        constraint = !lockbox.is_multisig() @ LockBoxError::MultisigRequired,
+        constraint = lockbox.has_guardian_approval(
+            &[guardian_1.as_ref(), guardian_2.as_ref(), guardian_3.as_ref()]
+        ) @ LockBoxError::GuardianApprovalRequired
     )]
     pub lockbox: Account<'info, LockBox>,
 
     #[account(mut)]
     pub owner: Signer<'info>,
 
+    // Guardian co-signers; as many registered guardians as the threshold must sign
+    pub guardian_1: Option<Signer<'info>>,
+    pub guardian_2: Option<Signer<'info>>,
+    pub guardian_3: Option<Signer<'info>>,
+
+    /// CHECK: Receives the withdrawal instead of the owner, checked against `lockbox.beneficiary`
+    #[account(mut)]
+    pub beneficiary: Option<AccountInfo<'info>>,
+
     /// CHECK: This is the PDA that holds the SOL
     #[account(
         mut,
@@ -25,10 +40,43 @@ pub struct EmergencyWithdraw<'info> {
     )]
     pub vault: AccountInfo<'info>,
 
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump
+    )]
+    pub config: Account<'info, Config>,
+
+    /// CHECK: Protocol treasury that collects early-exit penalties, checked against the config
+    #[account(
+        mut,
+        address = config.params.treasury @ LockBoxError::InvalidTreasury
+    )]
+    pub treasury: AccountInfo<'info>,
+
     pub system_program: Program<'info, System>,
 }
 
-pub fn emergency_withdraw(ctx: Context<EmergencyWithdraw>) -> Result<()> {
+pub fn emergency_withdraw(ctx: Context<EmergencyWithdraw>, keep_record: bool) -> Result<()> {
+    ctx.accounts.config.require_not_paused()?;
+
+    let clock = Clock::get()?;
+    ctx.accounts
+        .lockbox
+        .require_not_paused(clock.unix_timestamp)?;
+
+    // Contributors' money can only leave a crowdfund through refunds or a successful withdraw
+    require!(
+        !ctx.accounts.lockbox.is_crowdfund(),
+        LockBoxError::CrowdfundRestricted
+    );
+
+    ctx.accounts
+        .lockbox
+        .require_emergency_exit_allowed(clock.unix_timestamp)?;
+
+    // Breaking the lock is permanent; the LockBox stays as an inactive record unless closed below
+    ctx.accounts.lockbox.transition_to(LockBoxStatus::Broken)?;
+
     let lockbox = &ctx.accounts.lockbox;
 
     // Withdraw everything in the vault
@@ -37,27 +85,88 @@ pub fn emergency_withdraw(ctx: Context<EmergencyWithdraw>) -> Result<()> {
     // Check if there's anything to withdraw
     require!(withdraw_amount > 0, LockBoxError::InsufficientBalance);
 
-    // Transfer all SOL from vault to owner using CPI with signer seeds
+    // Breaking the lock early costs a share of the vault, decaying with time and progress
+    let penalty_bps = lockbox.early_exit_penalty_bps(clock.unix_timestamp);
+    let penalty = lockbox.early_exit_penalty(withdraw_amount, clock.unix_timestamp);
+    let owner_amount = withdraw_amount - penalty;
+
+    // Funds go to the beneficiary when the LockBox has one
+    let recipient = match &ctx.accounts.beneficiary {
+        Some(beneficiary) => beneficiary.to_account_info(),
+        None => ctx.accounts.owner.to_account_info(),
+    };
+    require_keys_eq!(
+        recipient.key(),
+        lockbox.payout_key(),
+        LockBoxError::InvalidBeneficiary
+    );
+
     let lockbox_key = lockbox.key();
     let vault_seeds = &[VAULT_SEED, lockbox_key.as_ref(), &[ctx.bumps.vault]];
     let signer_seeds = &[&vault_seeds[..]];
 
+    // Transfer the penalty from vault to treasury using CPI with signer seeds
+    if penalty > 0 {
+        let cpi_context = CpiContext::new_with_signer(
+            ctx.accounts.system_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.vault.to_account_info(),
+                to: ctx.accounts.treasury.to_account_info(),
+            },
+            signer_seeds,
+        );
+
+        transfer(cpi_context, penalty)?;
+    }
+
+    // Transfer the rest of the SOL from vault to the recipient
     let cpi_context = CpiContext::new_with_signer(
         ctx.accounts.system_program.to_account_info(),
         Transfer {
             from: ctx.accounts.vault.to_account_info(),
-            to: ctx.accounts.owner.to_account_info(),
+            to: recipient,
         },
         signer_seeds,
     );
 
-    transfer(cpi_context, withdraw_amount)?;
+    transfer(cpi_context, owner_amount)?;
+
+    let lockbox = &mut ctx.accounts.lockbox;
+    lockbox.record_emergency_withdrawal(owner_amount, penalty, clock.unix_timestamp);
+    lockbox.touch(clock.unix_timestamp);
 
     msg!(
-        "⚠️ Emergency withdrawal executed! Withdrawn {} lamports.",
-        withdraw_amount
+        "⚠️ Emergency withdrawal executed! Withdrawn {} lamports, penalty {} lamports ({} bps) sent to treasury.",
+        owner_amount,
+        penalty,
+        penalty_bps
     );
-    msg!("The LockBox account is now closed.");
+
+    emit_cpi!(EmergencyWithdrawn {
+        lockbox: lockbox.key(),
+        owner: lockbox.owner,
+        recipient: lockbox.payout_key(),
+        amount: owner_amount,
+        penalty,
+        timestamp: clock.unix_timestamp,
+    });
+
+    // Either keep an honest track record or reclaim the rent right away
+    if keep_record {
+        msg!("The LockBox is now permanently inactive.");
+    } else {
+        ctx.accounts
+            .lockbox
+            .close(ctx.accounts.owner.to_account_info())?;
+
+        msg!("LockBox closed. Rent lamports returned to owner.");
+
+        emit_cpi!(LockBoxClosed {
+            lockbox: ctx.accounts.lockbox.key(),
+            owner: ctx.accounts.owner.key(),
+            timestamp: clock.unix_timestamp,
+        });
+    }
 
     Ok(())
 }
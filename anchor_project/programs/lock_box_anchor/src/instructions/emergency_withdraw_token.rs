@@ -0,0 +1,230 @@
+use crate::errors::LockBoxError;
+use crate::events::{EmergencyWithdrawn, LockBoxClosed};
+use crate::states::{Config, LockBox, LockBoxStatus, CONFIG_SEED, LOCKBOX_SEED, VAULT_SEED};
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::spl_token_2022::extension::transfer_fee::TransferFeeConfig;
+use anchor_spl::token_interface::{
+    close_account, get_mint_extension_data, harvest_withheld_tokens_to_mint, transfer_checked,
+    CloseAccount, HarvestWithheldTokensToMint, Mint, TokenAccount, TokenInterface, TransferChecked,
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct EmergencyWithdrawToken<'info> {
+    #[account(
+        mut,
+        seeds = [LOCKBOX_SEED, lockbox.creator.as_ref(), lockbox.id.to_le_bytes().as_ref()],
+        bump = lockbox.bump,
+        has_one = owner @ LockBoxError::Unauthorized,
+        constraint = lockbox.mint == Some(mint.key()) @ LockBoxError::AssetMismatch,
+        constraint = lockbox.has_guardian_approval(
+            &[guardian_1.as_ref(), guardian_2.as_ref(), guardian_3.as_ref()]
+        ) @ LockBoxError::GuardianApprovalRequired
+    )]
+    pub lockbox: Account<'info, LockBox>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    // Guardian co-signers; as many registered guardians as the threshold must sign
+    pub guardian_1: Option<Signer<'info>>,
+    pub guardian_2: Option<Signer<'info>>,
+    pub guardian_3: Option<Signer<'info>>,
+
+    // Writable so withheld transfer fees can be harvested into it when the vault is closed
+    #[account(
+        mut,
+        mint::token_program = token_program
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = owner,
+        token::token_program = token_program
+    )]
+    pub owner_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    // Receives the withdrawal instead of the owner's account when the LockBox has a beneficiary
+    #[account(
+        mut,
+        token::mint = mint,
+        token::token_program = token_program
+    )]
+    pub beneficiary_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        seeds = [VAULT_SEED, lockbox.key().as_ref()],
+        bump
+    )]
+    pub vault: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump
+    )]
+    pub config: Account<'info, Config>,
+
+    // Treasury's token account for this mint, collects early-exit penalties
+    #[account(
+        mut,
+        token::mint = mint,
+        token::token_program = token_program,
+        constraint = treasury_token_account.owner == config.params.treasury @ LockBoxError::InvalidTreasury
+    )]
+    pub treasury_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+pub fn emergency_withdraw_token(
+    ctx: Context<EmergencyWithdrawToken>,
+    keep_record: bool,
+) -> Result<()> {
+    ctx.accounts.config.require_not_paused()?;
+
+    let clock = Clock::get()?;
+    ctx.accounts
+        .lockbox
+        .require_not_paused(clock.unix_timestamp)?;
+
+    ctx.accounts
+        .lockbox
+        .require_emergency_exit_allowed(clock.unix_timestamp)?;
+
+    // Breaking the lock is permanent; the LockBox stays as an inactive record unless closed below
+    ctx.accounts.lockbox.transition_to(LockBoxStatus::Broken)?;
+
+    let lockbox = &ctx.accounts.lockbox;
+
+    // Withdraw everything in the vault
+    let withdraw_amount = ctx.accounts.vault.amount;
+
+    // Check if there's anything to withdraw
+    require!(withdraw_amount > 0, LockBoxError::InsufficientBalance);
+
+    // Breaking the lock early costs a share of the vault, decaying with time and progress
+    let penalty_bps = lockbox.early_exit_penalty_bps(clock.unix_timestamp);
+    let penalty = lockbox.early_exit_penalty(withdraw_amount, clock.unix_timestamp);
+    let owner_amount = withdraw_amount - penalty;
+
+    // Tokens go to the beneficiary's account when the LockBox has one
+    let (recipient, recipient_owner) = match &ctx.accounts.beneficiary_token_account {
+        Some(account) => (account.to_account_info(), account.owner),
+        None => (
+            ctx.accounts.owner_token_account.to_account_info(),
+            ctx.accounts.owner.key(),
+        ),
+    };
+    require_keys_eq!(
+        recipient_owner,
+        lockbox.payout_key(),
+        LockBoxError::InvalidBeneficiary
+    );
+
+    let lockbox_key = lockbox.key();
+    let vault_seeds = &[VAULT_SEED, lockbox_key.as_ref(), &[ctx.bumps.vault]];
+    let signer_seeds = &[&vault_seeds[..]];
+    let decimals = ctx.accounts.mint.decimals;
+
+    // Transfer the penalty from vault to the treasury's token account
+    if penalty > 0 {
+        let cpi_context = CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            TransferChecked {
+                from: ctx.accounts.vault.to_account_info(),
+                mint: ctx.accounts.mint.to_account_info(),
+                to: ctx.accounts.treasury_token_account.to_account_info(),
+                authority: ctx.accounts.vault.to_account_info(),
+            },
+            signer_seeds,
+        );
+
+        transfer_checked(cpi_context, penalty, decimals)?;
+    }
+
+    // Transfer the rest of the tokens from vault to the recipient
+    let cpi_context = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        TransferChecked {
+            from: ctx.accounts.vault.to_account_info(),
+            mint: ctx.accounts.mint.to_account_info(),
+            to: recipient,
+            authority: ctx.accounts.vault.to_account_info(),
+        },
+        signer_seeds,
+    );
+
+    transfer_checked(cpi_context, owner_amount, decimals)?;
+
+    let lockbox = &mut ctx.accounts.lockbox;
+    lockbox.record_emergency_withdrawal(owner_amount, penalty, clock.unix_timestamp);
+    lockbox.touch(clock.unix_timestamp);
+
+    msg!(
+        "⚠️ Emergency withdrawal executed! Withdrawn {} tokens, penalty {} tokens ({} bps) sent to treasury.",
+        owner_amount,
+        penalty,
+        penalty_bps
+    );
+
+    emit_cpi!(EmergencyWithdrawn {
+        lockbox: lockbox.key(),
+        owner: lockbox.owner,
+        recipient: lockbox.payout_key(),
+        amount: owner_amount,
+        penalty,
+        timestamp: clock.unix_timestamp,
+    });
+
+    // Either keep an honest track record or reclaim the rent right away
+    if keep_record {
+        msg!("The LockBox is now permanently inactive.");
+    } else {
+        // Token-2022 refuses to close an account holding withheld transfer fees, so sweep them to the mint
+        let mint_info = ctx.accounts.mint.to_account_info();
+        if get_mint_extension_data::<TransferFeeConfig>(&mint_info).is_ok() {
+            let cpi_context = CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                HarvestWithheldTokensToMint {
+                    token_program_id: ctx.accounts.token_program.to_account_info(),
+                    mint: mint_info,
+                },
+            );
+
+            harvest_withheld_tokens_to_mint(
+                cpi_context,
+                vec![ctx.accounts.vault.to_account_info()],
+            )?;
+        }
+
+        // Close the empty token vault, signed by the vault PDA
+        let cpi_context = CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            CloseAccount {
+                account: ctx.accounts.vault.to_account_info(),
+                destination: ctx.accounts.owner.to_account_info(),
+                authority: ctx.accounts.vault.to_account_info(),
+            },
+            signer_seeds,
+        );
+
+        close_account(cpi_context)?;
+
+        ctx.accounts
+            .lockbox
+            .close(ctx.accounts.owner.to_account_info())?;
+
+        msg!("LockBox closed. Rent lamports returned to owner.");
+
+        emit_cpi!(LockBoxClosed {
+            lockbox: ctx.accounts.lockbox.key(),
+            owner: ctx.accounts.owner.key(),
+            timestamp: clock.unix_timestamp,
+        });
+    }
+
+    Ok(())
+}
@@ -0,0 +1,208 @@
+use crate::errors::LockBoxError;
+use crate::events::{LockBoxClosed, LockBoxPaused, LockBoxResumed, Withdrawn};
+use crate::states::{
+    vault_rent_minimum, Config, LockBox, LockBoxStatus, Proposal, ProposalAction, CONFIG_SEED,
+    LOCKBOX_SEED, PROPOSAL_SEED, VAULT_SEED,
+};
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ExecuteProposal<'info> {
+    #[account(
+        mut,
+        seeds = [LOCKBOX_SEED, lockbox.creator.as_ref(), lockbox.id.to_le_bytes().as_ref()],
+        bump = lockbox.bump,
+        has_one = owner @ LockBoxError::Unauthorized
+    )]
+    pub lockbox: Account<'info, LockBox>,
+
+    // Executed proposals are closed and their rent returned to the proposer
+    #[account(
+        mut,
+        seeds = [PROPOSAL_SEED, lockbox.key().as_ref(), proposal.id.to_le_bytes().as_ref()],
+        bump = proposal.bump,
+        has_one = lockbox,
+        has_one = proposer,
+        close = proposer
+    )]
+    pub proposal: Account<'info, Proposal>,
+
+    // Any signer of the multisig can execute once the threshold is met
+    #[account(
+        constraint = lockbox.signers.contains(&executor.key()) @ LockBoxError::NotMultisigSigner
+    )]
+    pub executor: Signer<'info>,
+
+    /// CHECK: Receives the proposal's rent back, checked against `proposal.proposer`
+    #[account(mut)]
+    pub proposer: AccountInfo<'info>,
+
+    /// CHECK: Receives withdrawals and the LockBox rent, checked against `lockbox.owner`
+    #[account(mut)]
+    pub owner: AccountInfo<'info>,
+
+    /// CHECK: Receives the withdrawal instead of the owner, checked against `lockbox.beneficiary`
+    #[account(mut)]
+    pub beneficiary: Option<AccountInfo<'info>>,
+
+    /// CHECK: This is the PDA that holds the SOL
+    #[account(
+        mut,
+        seeds = [VAULT_SEED, lockbox.key().as_ref()],
+        bump
+    )]
+    pub vault: AccountInfo<'info>,
+
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump
+    )]
+    pub config: Account<'info, Config>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn execute_proposal(ctx: Context<ExecuteProposal>) -> Result<()> {
+    ctx.accounts.config.require_not_paused()?;
+
+    // A resume proposal is the one action a paused LockBox still executes
+    let clock = Clock::get()?;
+    if ctx.accounts.proposal.action != ProposalAction::Resume {
+        ctx.accounts
+            .lockbox
+            .require_not_paused(clock.unix_timestamp)?;
+    }
+
+    // Only approvals from the current signer set count
+    let lockbox = &ctx.accounts.lockbox;
+    let approvals = ctx
+        .accounts
+        .proposal
+        .approvals
+        .iter()
+        .filter(|approval| lockbox.signers.contains(approval))
+        .count();
+    require!(
+        approvals >= lockbox.signer_threshold as usize,
+        LockBoxError::ThresholdNotMet
+    );
+
+    match ctx.accounts.proposal.action {
+        ProposalAction::Withdraw { amount } => {
+            // Same checks and vault signing as a direct withdrawal
+            ctx.accounts
+                .lockbox
+                .apply_withdrawal(amount, clock.unix_timestamp)?;
+
+            let remaining = ctx.accounts.vault.lamports().saturating_sub(amount);
+            require!(
+                remaining == 0 || remaining >= vault_rent_minimum()?,
+                LockBoxError::VaultBelowRentExemptMinimum
+            );
+
+            let recipient = match &ctx.accounts.beneficiary {
+                Some(beneficiary) => beneficiary.to_account_info(),
+                None => ctx.accounts.owner.to_account_info(),
+            };
+            require_keys_eq!(
+                recipient.key(),
+                ctx.accounts.lockbox.payout_key(),
+                LockBoxError::InvalidBeneficiary
+            );
+
+            let lockbox_key = ctx.accounts.lockbox.key();
+            let vault_seeds = &[VAULT_SEED, lockbox_key.as_ref(), &[ctx.bumps.vault]];
+            let signer_seeds = &[&vault_seeds[..]];
+
+            let cpi_context = CpiContext::new_with_signer(
+                ctx.accounts.system_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.vault.to_account_info(),
+                    to: recipient,
+                },
+                signer_seeds,
+            );
+
+            transfer(cpi_context, amount)?;
+
+            msg!(
+                "Proposal #{} executed: withdrawn {} lamports to {}. Remaining balance: {} lamports",
+                ctx.accounts.proposal.id,
+                amount,
+                ctx.accounts.lockbox.payout_key(),
+                ctx.accounts.lockbox.current_balance
+            );
+
+            emit_cpi!(Withdrawn {
+                lockbox: ctx.accounts.lockbox.key(),
+                owner: ctx.accounts.lockbox.owner,
+                recipient: ctx.accounts.lockbox.payout_key(),
+                amount,
+                balance: ctx.accounts.lockbox.current_balance,
+                timestamp: clock.unix_timestamp,
+            });
+        }
+        ProposalAction::Close => {
+            require!(
+                ctx.accounts.vault.lamports() == 0,
+                LockBoxError::InsufficientBalance
+            );
+
+            ctx.accounts.lockbox.transition_to(LockBoxStatus::Closed)?;
+            ctx.accounts
+                .lockbox
+                .close(ctx.accounts.owner.to_account_info())?;
+
+            msg!(
+                "Proposal #{} executed: LockBox closed. Rent lamports returned to owner.",
+                ctx.accounts.proposal.id
+            );
+
+            emit_cpi!(LockBoxClosed {
+                lockbox: ctx.accounts.lockbox.key(),
+                owner: ctx.accounts.owner.key(),
+                timestamp: clock.unix_timestamp,
+            });
+        }
+        ProposalAction::Pause { paused_until } => {
+            let lockbox = &mut ctx.accounts.lockbox;
+            lockbox.pause(paused_until, clock.unix_timestamp)?;
+
+            msg!(
+                "Proposal #{} executed: LockBox paused",
+                ctx.accounts.proposal.id
+            );
+
+            emit_cpi!(LockBoxPaused {
+                lockbox: lockbox.key(),
+                owner: lockbox.owner,
+                paused_until,
+                timestamp: clock.unix_timestamp,
+            });
+        }
+        ProposalAction::Resume => {
+            let lockbox = &mut ctx.accounts.lockbox;
+            lockbox.require_paused()?;
+            require!(
+                lockbox.guardians.is_empty(),
+                LockBoxError::GuardianApprovalRequired
+            );
+            lockbox.resume()?;
+
+            msg!(
+                "Proposal #{} executed: LockBox resumed",
+                ctx.accounts.proposal.id
+            );
+
+            emit_cpi!(LockBoxResumed {
+                lockbox: lockbox.key(),
+                owner: lockbox.owner,
+                timestamp: clock.unix_timestamp,
+            });
+        }
+    }
+
+    Ok(())
+}
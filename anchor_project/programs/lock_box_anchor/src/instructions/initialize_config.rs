@@ -0,0 +1,44 @@
+use crate::errors::LockBoxError;
+use crate::program::LockBoxAnchor;
+use crate::states::{Config, ConfigParams, CONFIG_SEED};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct InitializeConfig<'info> {
+    #[account(
+        init,
+        payer = admin,
+        space = Config::LEN,
+        seeds = [CONFIG_SEED],
+        bump
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(constraint = program.programdata_address()? == Some(program_data.key()))]
+    pub program: Program<'info, LockBoxAnchor>,
+
+    // Only the upgrade authority can create the config
+    #[account(
+        constraint = program_data.upgrade_authority_address == Some(admin.key()) @ LockBoxError::Unauthorized
+    )]
+    pub program_data: Account<'info, ProgramData>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn initialize_config(ctx: Context<InitializeConfig>, params: ConfigParams) -> Result<()> {
+    params.validate()?;
+
+    let config = &mut ctx.accounts.config;
+    config.admin = ctx.accounts.admin.key();
+    config.params = params;
+    config.lockbox_count = 0;
+    config.bump = ctx.bumps.config;
+
+    msg!("Config initialized. Admin: {}", config.admin);
+
+    Ok(())
+}
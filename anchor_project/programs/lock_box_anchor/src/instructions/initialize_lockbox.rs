@@ -1,14 +1,17 @@
 use crate::errors::LockBoxError;
-use crate::states::{LockBox, LOCKBOX_SEED};
+use crate::events::LockBoxCreated;
+use crate::states::{Config, LockBox, LockBoxParams, CONFIG_SEED, LOCKBOX_SEED};
 use anchor_lang::prelude::*;
 
+#[event_cpi]
 #[derive(Accounts)]
+#[instruction(lockbox_id: u64)]
 pub struct InitializeLockBox<'info> {
     #[account(
         init,
         payer = owner,
         space = LockBox::LEN,
-        seeds = [LOCKBOX_SEED, owner.key().as_ref()],
+        seeds = [LOCKBOX_SEED, owner.key().as_ref(), lockbox_id.to_le_bytes().as_ref()],
         bump
     )]
     pub lockbox: Account<'info, LockBox>,
@@ -16,23 +19,73 @@ pub struct InitializeLockBox<'info> {
     #[account(mut)]
     pub owner: Signer<'info>,
 
+    #[account(
+        mut,
+        seeds = [CONFIG_SEED],
+        bump = config.bump
+    )]
+    pub config: Account<'info, Config>,
+
     pub system_program: Program<'info, System>,
 }
 
-pub fn initialize_lockbox(ctx: Context<InitializeLockBox>, target_amount: u64) -> Result<()> {
-    require!(target_amount > 0, LockBoxError::InvalidTargetAmount);
+pub fn initialize_lockbox(
+    ctx: Context<InitializeLockBox>,
+    lockbox_id: u64,
+    params: LockBoxParams,
+) -> Result<()> {
+    let config = &ctx.accounts.config;
+    config.require_not_paused()?;
 
-    let lockbox = &mut ctx.accounts.lockbox;
     let clock = Clock::get()?;
+    params.validate(config, clock.unix_timestamp)?;
+    require!(
+        params.target_amount >= config.params.min_target_amount
+            && params.target_amount <= config.params.max_target_amount,
+        LockBoxError::TargetOutOfRange
+    );
+
+    let lockbox = &mut ctx.accounts.lockbox;
+    lockbox.init(
+        ctx.accounts.owner.key(),
+        lockbox_id,
+        None,
+        &params,
+        ctx.bumps.lockbox,
+        clock.unix_timestamp,
+    );
+    lockbox.nonce = ctx.accounts.config.next_lockbox_nonce();
 
-    lockbox.owner = ctx.accounts.owner.key();
-    lockbox.target_amount = target_amount;
-    lockbox.current_balance = 0;
-    lockbox.created_at = clock.unix_timestamp;
-    lockbox.has_reached_target = false;
-    lockbox.bump = ctx.bumps.lockbox;
+    msg!(
+        "LockBox #{} created with target: {} lamports",
+        lockbox_id,
+        params.target_amount
+    );
+    if let Some(unlock_at) = params.unlock_at {
+        msg!(
+            "Unlock policy {:?}, unlocks at {}",
+            params.unlock_policy,
+            unlock_at
+        );
+    }
+    if let Some(deadline) = params.crowdfund_deadline {
+        msg!("Crowdfund mode, deadline: {}", deadline);
+    }
+    if params.vesting_duration > 0 {
+        msg!(
+            "Funds vest over {} seconds after unlock",
+            params.vesting_duration
+        );
+    }
 
-    msg!("LockBox created with target: {} lamports", target_amount);
+    emit_cpi!(LockBoxCreated {
+        lockbox: ctx.accounts.lockbox.key(),
+        owner: ctx.accounts.owner.key(),
+        mint: None,
+        target_amount: params.target_amount,
+        unlock_at: params.unlock_at,
+        timestamp: clock.unix_timestamp,
+    });
 
     Ok(())
 }
@@ -0,0 +1,108 @@
+use crate::errors::LockBoxError;
+use crate::events::LockBoxCreated;
+use crate::states::{Config, LockBox, LockBoxParams, CONFIG_SEED, LOCKBOX_SEED, VAULT_SEED};
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(lockbox_id: u64)]
+pub struct InitializeTokenLockBox<'info> {
+    #[account(
+        init,
+        payer = owner,
+        space = LockBox::LEN,
+        seeds = [LOCKBOX_SEED, owner.key().as_ref(), lockbox_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub lockbox: Account<'info, LockBox>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(mint::token_program = token_program)]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    // Token account at the vault PDA that is its own authority, so it signs with the vault seeds
+    #[account(
+        init,
+        payer = owner,
+        seeds = [VAULT_SEED, lockbox.key().as_ref()],
+        bump,
+        token::mint = mint,
+        token::authority = vault,
+        token::token_program = token_program
+    )]
+    pub vault: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        seeds = [CONFIG_SEED],
+        bump = config.bump
+    )]
+    pub config: Account<'info, Config>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn initialize_token_lockbox(
+    ctx: Context<InitializeTokenLockBox>,
+    lockbox_id: u64,
+    params: LockBoxParams,
+) -> Result<()> {
+    let config = &ctx.accounts.config;
+    config.require_not_paused()?;
+
+    // Contributions and refunds move lamports, so crowdfunds stay SOL-only
+    require!(
+        params.crowdfund_deadline.is_none(),
+        LockBoxError::AssetMismatch
+    );
+
+    let clock = Clock::get()?;
+    params.validate(config, clock.unix_timestamp)?;
+
+    let mint = ctx.accounts.mint.key();
+    let lockbox = &mut ctx.accounts.lockbox;
+    lockbox.init(
+        ctx.accounts.owner.key(),
+        lockbox_id,
+        Some(mint),
+        &params,
+        ctx.bumps.lockbox,
+        clock.unix_timestamp,
+    );
+    lockbox.nonce = ctx.accounts.config.next_lockbox_nonce();
+
+    msg!(
+        "Token LockBox #{} created for mint {} with target: {}",
+        lockbox_id,
+        mint,
+        params.target_amount
+    );
+    if let Some(unlock_at) = params.unlock_at {
+        msg!(
+            "Unlock policy {:?}, unlocks at {}",
+            params.unlock_policy,
+            unlock_at
+        );
+    }
+    if params.vesting_duration > 0 {
+        msg!(
+            "Funds vest over {} seconds after unlock",
+            params.vesting_duration
+        );
+    }
+
+    emit_cpi!(LockBoxCreated {
+        lockbox: ctx.accounts.lockbox.key(),
+        owner: ctx.accounts.owner.key(),
+        mint: Some(mint),
+        target_amount: params.target_amount,
+        unlock_at: params.unlock_at,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -3,10 +3,61 @@ pub mod deposit;
 pub mod withdraw;
 pub mod emergency_withdraw;
 pub mod close_lockbox;
+pub mod initialize_config;
+pub mod update_config;
+pub mod transfer_admin;
+pub mod contribute;
+pub mod claim_refund;
+pub mod initialize_token_lockbox;
+pub mod deposit_token;
+pub mod withdraw_token;
+pub mod emergency_withdraw_token;
+pub mod close_token_lockbox;
+pub mod sync_balance;
+pub mod update_target;
+pub mod propose_owner;
+pub mod accept_owner;
+pub mod set_beneficiary;
+pub mod set_guardians;
+pub mod set_multisig;
+pub mod create_proposal;
+pub mod approve_proposal;
+pub mod execute_proposal;
+pub mod set_heir;
+pub mod claim_inheritance;
+pub mod set_withdrawal_limit;
+pub mod pause_lockbox;
+pub mod resume_lockbox;
+pub mod close_contribution;
 
 pub use initialize_lockbox::*;
 pub use deposit::*;
 pub use withdraw::*;
 pub use emergency_withdraw::*;
 pub use close_lockbox::*;
-
+pub use initialize_config::*;
+pub use update_config::*;
+pub use transfer_admin::*;
+pub use contribute::*;
+pub use claim_refund::*;
+pub use initialize_token_lockbox::*;
+pub use deposit_token::*;
+pub use withdraw_token::*;
+pub use emergency_withdraw_token::*;
+pub use close_token_lockbox::*;
+pub use sync_balance::*;
+pub use update_target::*;
+pub use propose_owner::*;
+pub use accept_owner::*;
+pub use set_beneficiary::*;
+pub use set_guardians::*;
+pub use set_multisig::*;
+pub use create_proposal::*;
+pub use approve_proposal::*;
+pub use execute_proposal::*;
+pub use set_heir::*;
+pub use claim_inheritance::*;
+pub use set_withdrawal_limit::*;
+pub use pause_lockbox::*;
+pub use resume_lockbox::*;
+pub use close_contribution::*;
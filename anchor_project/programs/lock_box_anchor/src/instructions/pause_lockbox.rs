@@ -0,0 +1,53 @@
+use crate::errors::LockBoxError;
+use crate::events::LockBoxPaused;
+use crate::states::{Config, LockBox, CONFIG_SEED, LOCKBOX_SEED};
+use anchor_lang::prelude::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct PauseLockBox<'info> {
+    #[account(
+        mut,
+        seeds = [LOCKBOX_SEED, lockbox.creator.as_ref(), lockbox.id.to_le_bytes().as_ref()],
+        bump = lockbox.bump,
+        has_one = owner @ LockBoxError::Unauthorized,
+        constraint = !lockbox.is_multisig() @ LockBoxError::MultisigRequired
+    )]
+    pub lockbox: Account<'info, LockBox>,
+
+    pub owner: Signer<'info>,
+
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump
+    )]
+    pub config: Account<'info, Config>,
+}
+
+pub fn pause_lockbox(ctx: Context<PauseLockBox>, paused_until: Option<i64>) -> Result<()> {
+    ctx.accounts.config.require_not_paused()?;
+
+    let clock = Clock::get()?;
+    ctx.accounts
+        .lockbox
+        .require_not_paused(clock.unix_timestamp)?;
+    ctx.accounts.lockbox.require_operational()?;
+
+    let lockbox = &mut ctx.accounts.lockbox;
+    lockbox.pause(paused_until, clock.unix_timestamp)?;
+    lockbox.touch(clock.unix_timestamp);
+
+    match paused_until {
+        Some(until) => msg!("LockBox #{} paused until {}", lockbox.id, until),
+        None => msg!("LockBox #{} paused until resumed", lockbox.id),
+    }
+
+    emit_cpi!(LockBoxPaused {
+        lockbox: lockbox.key(),
+        owner: lockbox.owner,
+        paused_until,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
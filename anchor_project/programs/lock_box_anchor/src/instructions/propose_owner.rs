@@ -0,0 +1,61 @@
+use crate::errors::LockBoxError;
+use crate::events::{LockBoxSetting, SettingsChanged};
+use crate::states::{Config, LockBox, CONFIG_SEED, LOCKBOX_SEED};
+use anchor_lang::prelude::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ProposeOwner<'info> {
+    #[account(
+        mut,
+        seeds = [LOCKBOX_SEED, lockbox.creator.as_ref(), lockbox.id.to_le_bytes().as_ref()],
+        bump = lockbox.bump,
+        has_one = owner @ LockBoxError::Unauthorized,
+        constraint = !lockbox.is_multisig() @ LockBoxError::MultisigRequired
+    )]
+    pub lockbox: Account<'info, LockBox>,
+
+    pub owner: Signer<'info>,
+
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump
+    )]
+    pub config: Account<'info, Config>,
+}
+
+pub fn propose_owner(ctx: Context<ProposeOwner>, new_owner: Option<Pubkey>) -> Result<()> {
+    ctx.accounts.config.require_not_paused()?;
+
+    let clock = Clock::get()?;
+    ctx.accounts
+        .lockbox
+        .require_not_paused(clock.unix_timestamp)?;
+    ctx.accounts.lockbox.require_operational()?;
+
+    // The owner only changes once the proposed wallet accepts; `None` cancels the proposal
+    let lockbox = &mut ctx.accounts.lockbox;
+    lockbox.pending_owner = new_owner;
+    lockbox.touch(clock.unix_timestamp);
+
+    match new_owner {
+        Some(new_owner) => msg!(
+            "Ownership of LockBox #{} proposed to {}",
+            lockbox.id,
+            new_owner
+        ),
+        None => msg!(
+            "Pending ownership transfer of LockBox #{} cancelled",
+            lockbox.id
+        ),
+    }
+
+    emit_cpi!(SettingsChanged {
+        lockbox: lockbox.key(),
+        owner: lockbox.owner,
+        setting: LockBoxSetting::PendingOwner,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,75 @@
+use crate::errors::LockBoxError;
+use crate::events::LockBoxResumed;
+use crate::states::{Config, LockBox, CONFIG_SEED, LOCKBOX_SEED};
+use anchor_lang::prelude::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ResumeLockBox<'info> {
+    #[account(
+        mut,
+        seeds = [LOCKBOX_SEED, lockbox.creator.as_ref(), lockbox.id.to_le_bytes().as_ref()],
+        bump = lockbox.bump
+    )]
+    pub lockbox: Account<'info, LockBox>,
+
+    // Resumes LockBoxes without guardians; not needed once `paused_until` has passed
+    pub owner: Option<Signer<'info>>,
+
+    // With guardians only they can resume, in case the pause was about a compromised owner key
+    pub guardian_1: Option<Signer<'info>>,
+    pub guardian_2: Option<Signer<'info>>,
+    pub guardian_3: Option<Signer<'info>>,
+
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump
+    )]
+    pub config: Account<'info, Config>,
+}
+
+pub fn resume_lockbox(ctx: Context<ResumeLockBox>) -> Result<()> {
+    ctx.accounts.config.require_not_paused()?;
+
+    let clock = Clock::get()?;
+    let lockbox = &ctx.accounts.lockbox;
+    lockbox.require_paused()?;
+
+    let owner_signed = ctx
+        .accounts
+        .owner
+        .as_ref()
+        .is_some_and(|owner| owner.key() == lockbox.owner);
+    if !lockbox.pause_expired(clock.unix_timestamp) {
+        if lockbox.guardians.is_empty() {
+            // The owner key alone can't undo a pause the signers agreed on
+            require!(!lockbox.is_multisig(), LockBoxError::MultisigRequired);
+            require!(owner_signed, LockBoxError::Unauthorized);
+        } else {
+            require!(
+                lockbox.has_guardian_approval(&[
+                    ctx.accounts.guardian_1.as_ref(),
+                    ctx.accounts.guardian_2.as_ref(),
+                    ctx.accounts.guardian_3.as_ref(),
+                ]),
+                LockBoxError::GuardianApprovalRequired
+            );
+        }
+    }
+
+    let lockbox = &mut ctx.accounts.lockbox;
+    lockbox.resume()?;
+    if owner_signed {
+        lockbox.touch(clock.unix_timestamp);
+    }
+
+    msg!("LockBox #{} resumed", lockbox.id);
+
+    emit_cpi!(LockBoxResumed {
+        lockbox: lockbox.key(),
+        owner: lockbox.owner,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,68 @@
+use crate::errors::LockBoxError;
+use crate::events::{LockBoxSetting, SettingsChanged};
+use crate::states::{Config, LockBox, CONFIG_SEED, LOCKBOX_SEED};
+use anchor_lang::prelude::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SetBeneficiary<'info> {
+    #[account(
+        mut,
+        seeds = [LOCKBOX_SEED, lockbox.creator.as_ref(), lockbox.id.to_le_bytes().as_ref()],
+        bump = lockbox.bump,
+        has_one = owner @ LockBoxError::Unauthorized,
+        constraint = !lockbox.is_multisig() @ LockBoxError::MultisigRequired
+    )]
+    pub lockbox: Account<'info, LockBox>,
+
+    pub owner: Signer<'info>,
+
+    // Must sign whenever the LockBox already has a beneficiary
+    pub current_beneficiary: Option<Signer<'info>>,
+
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump
+    )]
+    pub config: Account<'info, Config>,
+}
+
+pub fn set_beneficiary(ctx: Context<SetBeneficiary>, beneficiary: Option<Pubkey>) -> Result<()> {
+    ctx.accounts.config.require_not_paused()?;
+
+    let clock = Clock::get()?;
+    ctx.accounts
+        .lockbox
+        .require_not_paused(clock.unix_timestamp)?;
+    ctx.accounts.lockbox.require_operational()?;
+
+    // The owner alone can't take funds away from a beneficiary they promised them to
+    if let Some(current) = ctx.accounts.lockbox.beneficiary {
+        require!(
+            ctx.accounts
+                .current_beneficiary
+                .as_ref()
+                .is_some_and(|signer| signer.key() == current),
+            LockBoxError::BeneficiaryConsentRequired
+        );
+    }
+
+    let lockbox = &mut ctx.accounts.lockbox;
+    lockbox.beneficiary = beneficiary;
+    lockbox.touch(clock.unix_timestamp);
+
+    msg!(
+        "LockBox #{} withdrawals now go to {}",
+        lockbox.id,
+        lockbox.payout_key()
+    );
+
+    emit_cpi!(SettingsChanged {
+        lockbox: lockbox.key(),
+        owner: lockbox.owner,
+        setting: LockBoxSetting::Beneficiary,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
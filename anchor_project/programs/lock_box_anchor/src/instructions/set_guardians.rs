@@ -0,0 +1,68 @@
+use crate::errors::LockBoxError;
+use crate::events::{LockBoxSetting, SettingsChanged};
+use crate::states::{Config, LockBox, CONFIG_SEED, LOCKBOX_SEED};
+use anchor_lang::prelude::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SetGuardians<'info> {
+    // The current guardians must approve, otherwise the owner could simply remove them
+    #[account(
+        mut,
+        seeds = [LOCKBOX_SEED, lockbox.creator.as_ref(), lockbox.id.to_le_bytes().as_ref()],
+        bump = lockbox.bump,
+        has_one = owner @ LockBoxError::Unauthorized,
+        constraint = lockbox.has_guardian_approval(
+            &[guardian_1.as_ref(), guardian_2.as_ref(), guardian_3.as_ref()]
+        ) @ LockBoxError::GuardianApprovalRequired,
+        constraint = !lockbox.is_multisig() @ LockBoxError::MultisigRequired
+    )]
+    pub lockbox: Account<'info, LockBox>,
+
+    pub owner: Signer<'info>,
+
+    // Current guardian co-signers
+    pub guardian_1: Option<Signer<'info>>,
+    pub guardian_2: Option<Signer<'info>>,
+    pub guardian_3: Option<Signer<'info>>,
+
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump
+    )]
+    pub config: Account<'info, Config>,
+}
+
+pub fn set_guardians(
+    ctx: Context<SetGuardians>,
+    guardians: Vec<Pubkey>,
+    threshold: u8,
+) -> Result<()> {
+    ctx.accounts.config.require_not_paused()?;
+
+    let clock = Clock::get()?;
+    ctx.accounts
+        .lockbox
+        .require_not_paused(clock.unix_timestamp)?;
+    ctx.accounts.lockbox.require_operational()?;
+
+    let lockbox = &mut ctx.accounts.lockbox;
+    lockbox.set_guardians(guardians, threshold)?;
+    lockbox.touch(clock.unix_timestamp);
+
+    msg!(
+        "LockBox #{} now needs {} of {} guardians for emergency withdrawals",
+        lockbox.id,
+        lockbox.guardian_threshold,
+        lockbox.guardians.len()
+    );
+
+    emit_cpi!(SettingsChanged {
+        lockbox: lockbox.key(),
+        owner: lockbox.owner,
+        setting: LockBoxSetting::Guardians,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
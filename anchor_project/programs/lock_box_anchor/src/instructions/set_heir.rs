@@ -0,0 +1,89 @@
+use crate::errors::LockBoxError;
+use crate::events::{LockBoxSetting, SettingsChanged};
+use crate::states::{Config, LockBox, CONFIG_SEED, LOCKBOX_SEED};
+use anchor_lang::prelude::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SetHeir<'info> {
+    // A multisig already has other signers who can keep the funds moving
+    #[account(
+        mut,
+        seeds = [LOCKBOX_SEED, lockbox.creator.as_ref(), lockbox.id.to_le_bytes().as_ref()],
+        bump = lockbox.bump,
+        has_one = owner @ LockBoxError::Unauthorized,
+        constraint = !lockbox.is_multisig() @ LockBoxError::MultisigRequired
+    )]
+    pub lockbox: Account<'info, LockBox>,
+
+    pub owner: Signer<'info>,
+
+    // Inheriting clears the guardians and the beneficiary, so naming an heir needs their consent
+    pub guardian_1: Option<Signer<'info>>,
+    pub guardian_2: Option<Signer<'info>>,
+    pub guardian_3: Option<Signer<'info>>,
+    pub current_beneficiary: Option<Signer<'info>>,
+
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump
+    )]
+    pub config: Account<'info, Config>,
+}
+
+pub fn set_heir(ctx: Context<SetHeir>, heir: Option<Pubkey>, inactivity_period: i64) -> Result<()> {
+    ctx.accounts.config.require_not_paused()?;
+
+    let clock = Clock::get()?;
+    ctx.accounts
+        .lockbox
+        .require_not_paused(clock.unix_timestamp)?;
+    ctx.accounts.lockbox.require_operational()?;
+
+    // Otherwise the owner could name a second wallet of their own and shed both after the
+    // inactivity period. Removing the heir needs no consent.
+    if heir.is_some() {
+        let lockbox = &ctx.accounts.lockbox;
+        require!(
+            lockbox.has_guardian_approval(&[
+                ctx.accounts.guardian_1.as_ref(),
+                ctx.accounts.guardian_2.as_ref(),
+                ctx.accounts.guardian_3.as_ref(),
+            ]),
+            LockBoxError::GuardianApprovalRequired
+        );
+        if let Some(current) = lockbox.beneficiary {
+            require!(
+                ctx.accounts
+                    .current_beneficiary
+                    .as_ref()
+                    .is_some_and(|signer| signer.key() == current),
+                LockBoxError::BeneficiaryConsentRequired
+            );
+        }
+    }
+
+    let min_inactivity_period = ctx.accounts.config.params.min_inactivity_period;
+    let lockbox = &mut ctx.accounts.lockbox;
+    lockbox.set_heir(heir, inactivity_period, min_inactivity_period)?;
+    lockbox.touch(clock.unix_timestamp);
+
+    match heir {
+        Some(heir) => msg!(
+            "LockBox #{} passes to {} after {} seconds of owner inactivity",
+            lockbox.id,
+            heir,
+            inactivity_period
+        ),
+        None => msg!("Heir of LockBox #{} removed", lockbox.id),
+    }
+
+    emit_cpi!(SettingsChanged {
+        lockbox: lockbox.key(),
+        owner: lockbox.owner,
+        setting: LockBoxSetting::Heir,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,57 @@
+use crate::errors::LockBoxError;
+use crate::events::{LockBoxSetting, SettingsChanged};
+use crate::states::{Config, LockBox, CONFIG_SEED, LOCKBOX_SEED};
+use anchor_lang::prelude::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SetMultisig<'info> {
+    // One-way switch: once set, the signer set itself controls the funds
+    #[account(
+        mut,
+        seeds = [LOCKBOX_SEED, lockbox.creator.as_ref(), lockbox.id.to_le_bytes().as_ref()],
+        bump = lockbox.bump,
+        has_one = owner @ LockBoxError::Unauthorized,
+        constraint = !lockbox.is_token() @ LockBoxError::AssetMismatch,
+        constraint = !lockbox.is_multisig() @ LockBoxError::MultisigRequired
+    )]
+    pub lockbox: Account<'info, LockBox>,
+
+    pub owner: Signer<'info>,
+
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump
+    )]
+    pub config: Account<'info, Config>,
+}
+
+pub fn set_multisig(ctx: Context<SetMultisig>, signers: Vec<Pubkey>, threshold: u8) -> Result<()> {
+    ctx.accounts.config.require_not_paused()?;
+
+    let clock = Clock::get()?;
+    ctx.accounts
+        .lockbox
+        .require_not_paused(clock.unix_timestamp)?;
+    ctx.accounts.lockbox.require_operational()?;
+
+    let lockbox = &mut ctx.accounts.lockbox;
+    lockbox.set_signers(signers, threshold)?;
+    lockbox.touch(clock.unix_timestamp);
+
+    msg!(
+        "LockBox #{} now needs {} of {} signers to withdraw or close",
+        lockbox.id,
+        lockbox.signer_threshold,
+        lockbox.signers.len()
+    );
+
+    emit_cpi!(SettingsChanged {
+        lockbox: lockbox.key(),
+        owner: lockbox.owner,
+        setting: LockBoxSetting::Multisig,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,63 @@
+use crate::errors::LockBoxError;
+use crate::events::{LockBoxSetting, SettingsChanged};
+use crate::states::{Config, LockBox, CONFIG_SEED, LOCKBOX_SEED};
+use anchor_lang::prelude::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SetWithdrawalLimit<'info> {
+    #[account(
+        mut,
+        seeds = [LOCKBOX_SEED, lockbox.creator.as_ref(), lockbox.id.to_le_bytes().as_ref()],
+        bump = lockbox.bump,
+        has_one = owner @ LockBoxError::Unauthorized,
+        constraint = !lockbox.is_multisig() @ LockBoxError::MultisigRequired
+    )]
+    pub lockbox: Account<'info, LockBox>,
+
+    pub owner: Signer<'info>,
+
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump
+    )]
+    pub config: Account<'info, Config>,
+}
+
+pub fn set_withdrawal_limit(
+    ctx: Context<SetWithdrawalLimit>,
+    limit: u64,
+    window: i64,
+) -> Result<()> {
+    ctx.accounts.config.require_not_paused()?;
+
+    let clock = Clock::get()?;
+    ctx.accounts
+        .lockbox
+        .require_not_paused(clock.unix_timestamp)?;
+    ctx.accounts.lockbox.require_operational()?;
+
+    let lockbox = &mut ctx.accounts.lockbox;
+    lockbox.set_withdrawal_limit(limit, window)?;
+    lockbox.touch(clock.unix_timestamp);
+
+    if limit == 0 {
+        msg!("Withdrawal limit of LockBox #{} removed", lockbox.id);
+    } else {
+        msg!(
+            "LockBox #{} now allows withdrawing {} every {} seconds",
+            lockbox.id,
+            limit,
+            window
+        );
+    }
+
+    emit_cpi!(SettingsChanged {
+        lockbox: lockbox.key(),
+        owner: lockbox.owner,
+        setting: LockBoxSetting::WithdrawalLimit,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
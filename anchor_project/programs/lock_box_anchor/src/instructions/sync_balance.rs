@@ -0,0 +1,147 @@
+use crate::errors::LockBoxError;
+use crate::events::{Deposited, TargetReached, Withdrawn};
+use crate::states::{Config, LockBox, LockBoxStatus, CONFIG_SEED, LOCKBOX_SEED, VAULT_SEED};
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SyncBalance<'info> {
+    // Permissionless, so the seeds come from the account itself
+    #[account(
+        mut,
+        seeds = [LOCKBOX_SEED, lockbox.creator.as_ref(), lockbox.id.to_le_bytes().as_ref()],
+        bump = lockbox.bump,
+        has_one = owner @ LockBoxError::Unauthorized,
+        constraint = !lockbox.is_token() @ LockBoxError::AssetMismatch
+    )]
+    pub lockbox: Account<'info, LockBox>,
+
+    /// CHECK: Receives untracked lamports of a failed crowdfund, checked against `lockbox.owner`
+    #[account(mut)]
+    pub owner: AccountInfo<'info>,
+
+    /// CHECK: This is the PDA that holds the SOL
+    #[account(
+        mut,
+        seeds = [VAULT_SEED, lockbox.key().as_ref()],
+        bump
+    )]
+    pub vault: AccountInfo<'info>,
+
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump
+    )]
+    pub config: Account<'info, Config>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn sync_balance(ctx: Context<SyncBalance>) -> Result<()> {
+    ctx.accounts.config.require_not_paused()?;
+
+    let clock = Clock::get()?;
+    ctx.accounts
+        .lockbox
+        .require_not_paused(clock.unix_timestamp)?;
+
+    // Lamports sent to the empty vault of a Completed LockBox must not block closing it
+    let lockbox = &ctx.accounts.lockbox;
+    if lockbox.status != LockBoxStatus::Completed {
+        lockbox.require_operational()?;
+    }
+
+    // Crowdfund money must go through `contribute` so it can be refunded while the crowdfund runs
+    let crowdfund_failed = lockbox.crowdfund_failed(clock.unix_timestamp);
+    require!(
+        !lockbox.is_crowdfund() || lockbox.status != LockBoxStatus::Active || crowdfund_failed,
+        LockBoxError::CrowdfundRestricted
+    );
+
+    let vault_balance = ctx.accounts.vault.lamports();
+    let lockbox = &mut ctx.accounts.lockbox;
+
+    // Lamports sent straight to the vault are not tracked yet
+    let delta = vault_balance.saturating_sub(lockbox.current_balance);
+    if delta == 0 {
+        msg!(
+            "Vault already in sync: {} lamports",
+            lockbox.current_balance
+        );
+        return Ok(());
+    }
+
+    // The tracked balance of a failed crowdfund is exactly what its receipts refund, so
+    // untracked lamports go to the owner instead of keeping the vault from ever emptying
+    if crowdfund_failed {
+        let lockbox_key = lockbox.key();
+        let vault_seeds = &[VAULT_SEED, lockbox_key.as_ref(), &[ctx.bumps.vault]];
+        let signer_seeds = &[&vault_seeds[..]];
+
+        let cpi_context = CpiContext::new_with_signer(
+            ctx.accounts.system_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.vault.to_account_info(),
+                to: ctx.accounts.owner.to_account_info(),
+            },
+            signer_seeds,
+        );
+
+        transfer(cpi_context, delta)?;
+
+        msg!(
+            "Swept {} untracked lamports of the failed crowdfund to the owner",
+            delta
+        );
+
+        emit_cpi!(Withdrawn {
+            lockbox: lockbox_key,
+            owner: lockbox.owner,
+            recipient: lockbox.owner,
+            amount: delta,
+            balance: lockbox.current_balance,
+            timestamp: clock.unix_timestamp,
+        });
+
+        return Ok(());
+    }
+
+    let target_was_reached = lockbox.has_reached_target();
+    lockbox.current_balance = vault_balance;
+
+    // Reopen a Completed LockBox so the owner can withdraw the new lamports and then close it
+    if lockbox.status == LockBoxStatus::Completed {
+        lockbox.transition_to(LockBoxStatus::Unlocked)?;
+    }
+
+    msg!(
+        "Synced {} untracked lamports. Current balance: {} / {} lamports",
+        delta,
+        lockbox.current_balance,
+        lockbox.target_amount
+    );
+
+    lockbox.check_target_reached(clock.unix_timestamp)?;
+
+    // The sender of untracked lamports is unknown, so the vault stands in as depositor
+    emit_cpi!(Deposited {
+        lockbox: lockbox.key(),
+        owner: lockbox.owner,
+        depositor: ctx.accounts.vault.key(),
+        amount: delta,
+        balance: lockbox.current_balance,
+        timestamp: clock.unix_timestamp,
+    });
+    if !target_was_reached && lockbox.has_reached_target() {
+        emit_cpi!(TargetReached {
+            lockbox: lockbox.key(),
+            owner: lockbox.owner,
+            balance: lockbox.current_balance,
+            target_amount: lockbox.target_amount,
+            timestamp: clock.unix_timestamp,
+        });
+    }
+
+    Ok(())
+}
@@ -0,0 +1,28 @@
+use crate::errors::LockBoxError;
+use crate::states::{Config, CONFIG_SEED};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct TransferAdmin<'info> {
+    #[account(
+        mut,
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+        has_one = admin @ LockBoxError::Unauthorized
+    )]
+    pub config: Account<'info, Config>,
+
+    pub admin: Signer<'info>,
+
+    // The new admin signs too, so the config can't be handed to a wrong key
+    pub new_admin: Signer<'info>,
+}
+
+pub fn transfer_admin(ctx: Context<TransferAdmin>) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    config.admin = ctx.accounts.new_admin.key();
+
+    msg!("Config admin transferred to {}", config.admin);
+
+    Ok(())
+}
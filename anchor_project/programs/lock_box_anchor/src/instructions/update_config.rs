@@ -0,0 +1,27 @@
+use crate::errors::LockBoxError;
+use crate::states::{Config, ConfigParams, CONFIG_SEED};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct UpdateConfig<'info> {
+    #[account(
+        mut,
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+        has_one = admin @ LockBoxError::Unauthorized
+    )]
+    pub config: Account<'info, Config>,
+
+    pub admin: Signer<'info>,
+}
+
+pub fn update_config(ctx: Context<UpdateConfig>, params: ConfigParams) -> Result<()> {
+    params.validate()?;
+
+    let config = &mut ctx.accounts.config;
+    config.params = params;
+
+    msg!("Config updated. Paused: {}", params.paused);
+
+    Ok(())
+}
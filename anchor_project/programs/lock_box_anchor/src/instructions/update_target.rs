@@ -0,0 +1,188 @@
+use crate::errors::LockBoxError;
+use crate::events::{LockBoxSetting, SettingsChanged, TargetReached};
+use crate::states::{
+    vault_rent_minimum, Config, LockBox, LockBoxStatus, CONFIG_SEED, LOCKBOX_SEED,
+    TARGET_DECREASE_COOLDOWN, VAULT_SEED,
+};
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct UpdateTarget<'info> {
+    #[account(
+        mut,
+        seeds = [LOCKBOX_SEED, lockbox.creator.as_ref(), lockbox.id.to_le_bytes().as_ref()],
+        bump = lockbox.bump,
+        has_one = owner @ LockBoxError::Unauthorized,
+        constraint = !lockbox.is_multisig() @ LockBoxError::MultisigRequired
+    )]
+    pub lockbox: Account<'info, LockBox>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    // Guardian co-signers; lowering the target needs the same approval as an emergency withdrawal
+    pub guardian_1: Option<Signer<'info>>,
+    pub guardian_2: Option<Signer<'info>>,
+    pub guardian_3: Option<Signer<'info>>,
+
+    /// CHECK: This is the PDA that holds the SOL, only touched when paying the penalty
+    #[account(
+        mut,
+        seeds = [VAULT_SEED, lockbox.key().as_ref()],
+        bump
+    )]
+    pub vault: AccountInfo<'info>,
+
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump
+    )]
+    pub config: Account<'info, Config>,
+
+    /// CHECK: Protocol treasury that collects early-exit penalties, checked against the config
+    #[account(
+        mut,
+        address = config.params.treasury @ LockBoxError::InvalidTreasury
+    )]
+    pub treasury: AccountInfo<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn update_target(ctx: Context<UpdateTarget>, new_target: u64, pay_penalty: bool) -> Result<()> {
+    require!(new_target > 0, LockBoxError::InvalidTargetAmount);
+
+    let config = &ctx.accounts.config;
+    config.require_not_paused()?;
+
+    let clock = Clock::get()?;
+    ctx.accounts
+        .lockbox
+        .require_not_paused(clock.unix_timestamp)?;
+    ctx.accounts.lockbox.require_operational()?;
+
+    let lockbox = &ctx.accounts.lockbox;
+
+    // Once unlocked the target no longer matters
+    require!(
+        lockbox.status == LockBoxStatus::Active,
+        LockBoxError::InvalidStatusTransition
+    );
+
+    // Contributors backed the original all-or-nothing target
+    require!(!lockbox.is_crowdfund(), LockBoxError::CrowdfundRestricted);
+
+    if !lockbox.is_token() {
+        require!(
+            new_target >= config.params.min_target_amount
+                && new_target <= config.params.max_target_amount,
+            LockBoxError::TargetOutOfRange
+        );
+    }
+
+    let old_target = lockbox.target_amount;
+
+    // Lowering the target inside the cooldown, or far enough to unlock the LockBox, costs
+    // what breaking the lock would cost right now. Otherwise lowering would be a free unlock.
+    let lowering = new_target < old_target;
+    require!(
+        !lowering
+            || lockbox.has_guardian_approval(&[
+                ctx.accounts.guardian_1.as_ref(),
+                ctx.accounts.guardian_2.as_ref(),
+                ctx.accounts.guardian_3.as_ref(),
+            ]),
+        LockBoxError::GuardianApprovalRequired
+    );
+
+    let unlocks = lowering
+        && lockbox.check_unlocked(clock.unix_timestamp).is_err()
+        && lockbox
+            .check_unlocked_with_target(new_target, clock.unix_timestamp)
+            .is_ok();
+    let cooldown_ends = lockbox.target_updated_at + TARGET_DECREASE_COOLDOWN;
+    if unlocks || (lowering && clock.unix_timestamp < cooldown_ends) {
+        require!(
+            pay_penalty && !lockbox.is_token(),
+            if unlocks {
+                LockBoxError::TargetDecreaseUnlocks
+            } else {
+                LockBoxError::TargetCooldownActive
+            }
+        );
+
+        let penalty_bps = lockbox.early_exit_penalty_bps(clock.unix_timestamp);
+        let penalty = lockbox.early_exit_penalty(lockbox.current_balance, clock.unix_timestamp);
+
+        if penalty > 0 {
+            let remaining = ctx.accounts.vault.lamports().saturating_sub(penalty);
+            require!(
+                remaining == 0 || remaining >= vault_rent_minimum()?,
+                LockBoxError::VaultBelowRentExemptMinimum
+            );
+
+            // Transfer the penalty from vault to treasury using CPI with signer seeds
+            let lockbox_key = lockbox.key();
+            let vault_seeds = &[VAULT_SEED, lockbox_key.as_ref(), &[ctx.bumps.vault]];
+            let signer_seeds = &[&vault_seeds[..]];
+
+            let cpi_context = CpiContext::new_with_signer(
+                ctx.accounts.system_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.vault.to_account_info(),
+                    to: ctx.accounts.treasury.to_account_info(),
+                },
+                signer_seeds,
+            );
+
+            transfer(cpi_context, penalty)?;
+
+            let lockbox = &mut ctx.accounts.lockbox;
+            lockbox.current_balance -= penalty;
+        }
+
+        msg!(
+            "Target lowered early, penalty {} lamports ({} bps) sent to treasury.",
+            penalty,
+            penalty_bps
+        );
+    }
+
+    let lockbox = &mut ctx.accounts.lockbox;
+    let target_was_reached = lockbox.has_reached_target();
+    lockbox.target_amount = new_target;
+    lockbox.highest_target = lockbox.highest_target.max(new_target);
+    lockbox.target_updated_at = clock.unix_timestamp;
+    lockbox.touch(clock.unix_timestamp);
+
+    msg!(
+        "Target updated from {} to {}. Current balance: {}",
+        old_target,
+        new_target,
+        lockbox.current_balance
+    );
+
+    emit_cpi!(SettingsChanged {
+        lockbox: lockbox.key(),
+        owner: lockbox.owner,
+        setting: LockBoxSetting::Target,
+        timestamp: clock.unix_timestamp,
+    });
+
+    // A lower target may already be met
+    lockbox.check_target_reached(clock.unix_timestamp)?;
+
+    if !target_was_reached && lockbox.has_reached_target() {
+        emit_cpi!(TargetReached {
+            lockbox: lockbox.key(),
+            owner: lockbox.owner,
+            balance: lockbox.current_balance,
+            target_amount: lockbox.target_amount,
+            timestamp: clock.unix_timestamp,
+        });
+    }
+
+    Ok(())
+}
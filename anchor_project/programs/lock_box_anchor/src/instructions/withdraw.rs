@@ -1,21 +1,29 @@
 use crate::errors::LockBoxError;
-use crate::states::{LockBox, LOCKBOX_SEED, VAULT_SEED};
+use crate::events::Withdrawn;
+use crate::states::{vault_rent_minimum, Config, LockBox, CONFIG_SEED, LOCKBOX_SEED, VAULT_SEED};
 use anchor_lang::prelude::*;
 use anchor_lang::system_program::{transfer, Transfer};
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct Withdraw<'info> {
     #[account(
         mut,
-        seeds = [LOCKBOX_SEED, owner.key().as_ref()],
+        seeds = [LOCKBOX_SEED, lockbox.creator.as_ref(), lockbox.id.to_le_bytes().as_ref()],
         bump = lockbox.bump,
-        has_one = owner @ LockBoxError::Unauthorized
+        has_one = owner @ LockBoxError::Unauthorized,
+        constraint = !lockbox.is_token() @ LockBoxError::AssetMismatch,
+        constraint = !lockbox.is_multisig() @ LockBoxError::MultisigRequired
     )]
     pub lockbox: Account<'info, LockBox>,
 
     #[account(mut)]
     pub owner: Signer<'info>,
 
+    /// CHECK: Receives the withdrawal instead of the owner, checked against `lockbox.beneficiary`
+    #[account(mut)]
+    pub beneficiary: Option<AccountInfo<'info>>,
+
     /// CHECK: This is the PDA that holds the SOL
     #[account(
         mut,
@@ -24,23 +32,49 @@ pub struct Withdraw<'info> {
     )]
     pub vault: AccountInfo<'info>,
 
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump
+    )]
+    pub config: Account<'info, Config>,
+
     pub system_program: Program<'info, System>,
 }
 
 pub fn withdraw(ctx: Context<Withdraw>, amount: u64) -> Result<()> {
-    let lockbox = &ctx.accounts.lockbox;
+    ctx.accounts.config.require_not_paused()?;
+
+    let clock = Clock::get()?;
+    ctx.accounts
+        .lockbox
+        .require_not_paused(clock.unix_timestamp)?;
 
-    // Check if target has been reached
-    require!(lockbox.has_reached_target, LockBoxError::TargetNotReached);
+    // Debit the LockBox first; the unlock policy and balance are checked here
+    ctx.accounts
+        .lockbox
+        .apply_withdrawal(amount, clock.unix_timestamp)?;
+    ctx.accounts.lockbox.touch(clock.unix_timestamp);
 
-    // Check if vault has sufficient balance
+    // Leaving dust below the rent-exempt minimum would fail in the runtime
+    let remaining = ctx.accounts.vault.lamports().saturating_sub(amount);
     require!(
-        lockbox.current_balance >= amount,
-        LockBoxError::InsufficientBalance
+        remaining == 0 || remaining >= vault_rent_minimum()?,
+        LockBoxError::VaultBelowRentExemptMinimum
     );
 
-    // Transfer SOL from vault to owner using CPI with signer seeds
-    let lockbox_key = lockbox.key();
+    // Funds go to the beneficiary when the LockBox has one
+    let recipient = match &ctx.accounts.beneficiary {
+        Some(beneficiary) => beneficiary.to_account_info(),
+        None => ctx.accounts.owner.to_account_info(),
+    };
+    require_keys_eq!(
+        recipient.key(),
+        ctx.accounts.lockbox.payout_key(),
+        LockBoxError::InvalidBeneficiary
+    );
+
+    // Transfer SOL from vault to the recipient using CPI with signer seeds
+    let lockbox_key = ctx.accounts.lockbox.key();
     let vault_seeds = &[VAULT_SEED, lockbox_key.as_ref(), &[ctx.bumps.vault]];
     let signer_seeds = &[&vault_seeds[..]];
 
@@ -48,22 +82,28 @@ pub fn withdraw(ctx: Context<Withdraw>, amount: u64) -> Result<()> {
         ctx.accounts.system_program.to_account_info(),
         Transfer {
             from: ctx.accounts.vault.to_account_info(),
-            to: ctx.accounts.owner.to_account_info(),
+            to: recipient,
         },
         signer_seeds,
     );
 
     transfer(cpi_context, amount)?;
 
-    // Update lockbox balance
-    let lockbox = &mut ctx.accounts.lockbox;
-    lockbox.current_balance = lockbox.current_balance.checked_sub(amount).unwrap();
-
     msg!(
-        "Withdrawn {} lamports. Remaining balance: {} lamports",
+        "Withdrawn {} lamports to {}. Remaining balance: {} lamports",
         amount,
-        lockbox.current_balance
+        ctx.accounts.lockbox.payout_key(),
+        ctx.accounts.lockbox.current_balance
     );
 
+    emit_cpi!(Withdrawn {
+        lockbox: ctx.accounts.lockbox.key(),
+        owner: ctx.accounts.lockbox.owner,
+        recipient: ctx.accounts.lockbox.payout_key(),
+        amount,
+        balance: ctx.accounts.lockbox.current_balance,
+        timestamp: clock.unix_timestamp,
+    });
+
     Ok(())
 }
@@ -0,0 +1,120 @@
+use crate::errors::LockBoxError;
+use crate::events::Withdrawn;
+use crate::states::{Config, LockBox, CONFIG_SEED, LOCKBOX_SEED, VAULT_SEED};
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{
+    transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked,
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct WithdrawToken<'info> {
+    #[account(
+        mut,
+        seeds = [LOCKBOX_SEED, lockbox.creator.as_ref(), lockbox.id.to_le_bytes().as_ref()],
+        bump = lockbox.bump,
+        has_one = owner @ LockBoxError::Unauthorized,
+        constraint = lockbox.mint == Some(mint.key()) @ LockBoxError::AssetMismatch
+    )]
+    pub lockbox: Account<'info, LockBox>,
+
+    pub owner: Signer<'info>,
+
+    #[account(mint::token_program = token_program)]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = owner,
+        token::token_program = token_program
+    )]
+    pub owner_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    // Receives the withdrawal instead of the owner's account when the LockBox has a beneficiary
+    #[account(
+        mut,
+        token::mint = mint,
+        token::token_program = token_program
+    )]
+    pub beneficiary_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        seeds = [VAULT_SEED, lockbox.key().as_ref()],
+        bump
+    )]
+    pub vault: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump
+    )]
+    pub config: Account<'info, Config>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+pub fn withdraw_token(ctx: Context<WithdrawToken>, amount: u64) -> Result<()> {
+    ctx.accounts.config.require_not_paused()?;
+
+    let clock = Clock::get()?;
+    ctx.accounts
+        .lockbox
+        .require_not_paused(clock.unix_timestamp)?;
+
+    // Debit the LockBox first; the unlock policy and balance are checked here
+    ctx.accounts
+        .lockbox
+        .apply_withdrawal(amount, clock.unix_timestamp)?;
+    ctx.accounts.lockbox.touch(clock.unix_timestamp);
+
+    // Tokens go to the beneficiary's account when the LockBox has one
+    let (recipient, recipient_owner) = match &ctx.accounts.beneficiary_token_account {
+        Some(account) => (account.to_account_info(), account.owner),
+        None => (
+            ctx.accounts.owner_token_account.to_account_info(),
+            ctx.accounts.owner.key(),
+        ),
+    };
+    require_keys_eq!(
+        recipient_owner,
+        ctx.accounts.lockbox.payout_key(),
+        LockBoxError::InvalidBeneficiary
+    );
+
+    // Transfer tokens from vault to the recipient, signed by the vault PDA
+    let lockbox_key = ctx.accounts.lockbox.key();
+    let vault_seeds = &[VAULT_SEED, lockbox_key.as_ref(), &[ctx.bumps.vault]];
+    let signer_seeds = &[&vault_seeds[..]];
+
+    let cpi_context = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        TransferChecked {
+            from: ctx.accounts.vault.to_account_info(),
+            mint: ctx.accounts.mint.to_account_info(),
+            to: recipient,
+            authority: ctx.accounts.vault.to_account_info(),
+        },
+        signer_seeds,
+    );
+
+    transfer_checked(cpi_context, amount, ctx.accounts.mint.decimals)?;
+
+    msg!(
+        "Withdrawn {} tokens. Remaining balance: {} tokens",
+        amount,
+        ctx.accounts.lockbox.current_balance
+    );
+
+    emit_cpi!(Withdrawn {
+        lockbox: ctx.accounts.lockbox.key(),
+        owner: ctx.accounts.lockbox.owner,
+        recipient: ctx.accounts.lockbox.payout_key(),
+        amount,
+        balance: ctx.accounts.lockbox.current_balance,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -1,10 +1,12 @@
 use anchor_lang::prelude::*;
 
 pub mod errors;
+pub mod events;
 pub mod instructions;
 pub mod states;
 
 use instructions::*;
+use states::{ConfigParams, LockBoxParams, ProposalAction};
 
 declare_id!("FkFyFob5oYm4Q9aukvK1ttXduveWh16HYmhCvMXyw6tr");
 
@@ -12,9 +14,16 @@ declare_id!("FkFyFob5oYm4Q9aukvK1ttXduveWh16HYmhCvMXyw6tr");
 pub mod lock_box_anchor {
     use super::*;
 
-    /// Initialize a new LockBox vault with a target amount
-    pub fn initialize_lockbox(ctx: Context<InitializeLockBox>, target_amount: u64) -> Result<()> {
-        instructions::initialize_lockbox(ctx, target_amount)
+    /// Initialize a new SOL LockBox vault with a target amount, unlock policy and
+    /// early-exit penalty schedule. `lockbox_id` lets one owner keep several independent LockBoxes.
+    /// A `crowdfund_deadline` turns the LockBox into an all-or-nothing crowdfund, and a
+    /// `beneficiary` receives withdrawals instead of the owner.
+    pub fn initialize_lockbox(
+        ctx: Context<InitializeLockBox>,
+        lockbox_id: u64,
+        params: LockBoxParams,
+    ) -> Result<()> {
+        instructions::initialize_lockbox(ctx, lockbox_id, params)
     }
 
     /// Deposit SOL into the LockBox vault
@@ -22,18 +31,192 @@ pub mod lock_box_anchor {
         instructions::deposit(ctx, amount)
     }
 
-    /// Withdraw SOL from the LockBox vault (only when target is reached)
+    /// Contribute SOL to someone else's LockBox (any signer; only the owner can withdraw)
+    pub fn contribute(ctx: Context<Contribute>, amount: u64) -> Result<()> {
+        instructions::contribute(ctx, amount)
+    }
+
+    /// Refund a contributor's recorded total after a crowdfund missed its target by the deadline
+    pub fn claim_refund(ctx: Context<ClaimRefund>) -> Result<()> {
+        instructions::claim_refund(ctx)
+    }
+
+    /// Withdraw SOL from the LockBox vault (only once the unlock policy is satisfied)
     pub fn withdraw(ctx: Context<Withdraw>, amount: u64) -> Result<()> {
         instructions::withdraw(ctx, amount)
     }
 
-    /// Emergency withdrawal - withdraws all funds but deactivates the vault permanently
-    pub fn emergency_withdraw(ctx: Context<EmergencyWithdraw>) -> Result<()> {
-        instructions::emergency_withdraw(ctx)
+    /// Emergency withdrawal - withdraws all funds minus the penalty (sent to the treasury).
+    /// With `keep_record` the LockBox stays as a permanently broken record, otherwise it is
+    /// closed and its rent returned. Guardians must co-sign if registered.
+    pub fn emergency_withdraw(ctx: Context<EmergencyWithdraw>, keep_record: bool) -> Result<()> {
+        instructions::emergency_withdraw(ctx, keep_record)
     }
 
-    /// Close the LockBox account and reclaim rent (vault must be empty)
+    /// Close the LockBox account and reclaim rent (vault must be empty, LockBox not paused)
     pub fn close_lockbox(ctx: Context<CloseLockBox>) -> Result<()> {
         instructions::close_lockbox(ctx)
     }
+
+    /// Change the target of an Active LockBox. Raising is free; lowering needs the guardians'
+    /// approval, and either the cooldown since the last change to have passed without unlocking
+    /// the LockBox, or `pay_penalty` to pay the current early-exit penalty
+    pub fn update_target(
+        ctx: Context<UpdateTarget>,
+        new_target: u64,
+        pay_penalty: bool,
+    ) -> Result<()> {
+        instructions::update_target(ctx, new_target, pay_penalty)
+    }
+
+    /// Propose a new owner for the LockBox (or cancel with `None`); takes effect on acceptance
+    pub fn propose_owner(ctx: Context<ProposeOwner>, new_owner: Option<Pubkey>) -> Result<()> {
+        instructions::propose_owner(ctx, new_owner)
+    }
+
+    /// Accept a pending ownership transfer (signed by the proposed owner)
+    pub fn accept_owner(ctx: Context<AcceptOwner>) -> Result<()> {
+        instructions::accept_owner(ctx)
+    }
+
+    /// Set or clear the beneficiary that receives withdrawals; an existing beneficiary must co-sign
+    pub fn set_beneficiary(
+        ctx: Context<SetBeneficiary>,
+        beneficiary: Option<Pubkey>,
+    ) -> Result<()> {
+        instructions::set_beneficiary(ctx, beneficiary)
+    }
+
+    /// Register up to three guardians and how many of them must co-sign an emergency
+    /// withdrawal. Changing an existing set needs the current guardians' approval.
+    pub fn set_guardians(
+        ctx: Context<SetGuardians>,
+        guardians: Vec<Pubkey>,
+        threshold: u8,
+    ) -> Result<()> {
+        instructions::set_guardians(ctx, guardians, threshold)
+    }
+
+    /// Hand a SOL LockBox over to an m-of-n signer set; from then on withdrawals
+    /// and closing only happen through approved proposals.
+    pub fn set_multisig(
+        ctx: Context<SetMultisig>,
+        signers: Vec<Pubkey>,
+        threshold: u8,
+    ) -> Result<()> {
+        instructions::set_multisig(ctx, signers, threshold)
+    }
+
+    /// Propose a withdrawal, closing, pause or resume of a multisig LockBox (counts as the
+    /// proposer's approval)
+    pub fn create_proposal(ctx: Context<CreateProposal>, action: ProposalAction) -> Result<()> {
+        instructions::create_proposal(ctx, action)
+    }
+
+    /// Add a signer's approval to a pending proposal
+    pub fn approve_proposal(ctx: Context<ApproveProposal>) -> Result<()> {
+        instructions::approve_proposal(ctx)
+    }
+
+    /// Carry out a proposal that reached the threshold, signing with the vault PDA
+    pub fn execute_proposal(ctx: Context<ExecuteProposal>) -> Result<()> {
+        instructions::execute_proposal(ctx)
+    }
+
+    /// Name an heir who can take over the LockBox once the owner has been inactive for
+    /// `inactivity_period` seconds (`None` removes the heir). Requires guardian approval and the
+    /// current beneficiary's signature when the LockBox has them.
+    pub fn set_heir(
+        ctx: Context<SetHeir>,
+        heir: Option<Pubkey>,
+        inactivity_period: i64,
+    ) -> Result<()> {
+        instructions::set_heir(ctx, heir, inactivity_period)
+    }
+
+    /// Let the heir become the owner after the inactivity period has passed
+    pub fn claim_inheritance(ctx: Context<ClaimInheritance>) -> Result<()> {
+        instructions::claim_inheritance(ctx)
+    }
+
+    /// Cap withdrawals at `limit` per rolling `window` of seconds (e.g. a weekly budget); 0/0 removes the cap.
+    /// Once the LockBox is unlocked the cap can only be tightened.
+    pub fn set_withdrawal_limit(
+        ctx: Context<SetWithdrawalLimit>,
+        limit: u64,
+        window: i64,
+    ) -> Result<()> {
+        instructions::set_withdrawal_limit(ctx, limit, window)
+    }
+
+    /// Freeze the LockBox (no deposits, withdrawals or emergency exits), e.g. while travelling
+    /// or after a suspected key compromise. `paused_until` ends the pause automatically.
+    pub fn pause_lockbox(ctx: Context<PauseLockBox>, paused_until: Option<i64>) -> Result<()> {
+        instructions::pause_lockbox(ctx, paused_until)
+    }
+
+    /// Resume a paused LockBox: by the guardians if it has any, otherwise by the owner.
+    /// Multisig LockBoxes without guardians resume through a proposal.
+    pub fn resume_lockbox(ctx: Context<ResumeLockBox>) -> Result<()> {
+        instructions::resume_lockbox(ctx)
+    }
+
+    /// Close a contribution receipt and return its rent to the contributor (not while a
+    /// crowdfund may still need it for refunds)
+    pub fn close_contribution(ctx: Context<CloseContribution>) -> Result<()> {
+        instructions::close_contribution(ctx)
+    }
+
+    /// Fold lamports sent straight to the vault into the tracked balance (anyone can call).
+    /// A failed crowdfund pays them to the owner instead.
+    pub fn sync_balance(ctx: Context<SyncBalance>) -> Result<()> {
+        instructions::sync_balance(ctx)
+    }
+
+    /// Initialize a LockBox that saves an SPL token; its vault is a token account owned by the vault PDA
+    pub fn initialize_token_lockbox(
+        ctx: Context<InitializeTokenLockBox>,
+        lockbox_id: u64,
+        params: LockBoxParams,
+    ) -> Result<()> {
+        instructions::initialize_token_lockbox(ctx, lockbox_id, params)
+    }
+
+    /// Deposit tokens into a token LockBox vault
+    pub fn deposit_token(ctx: Context<DepositToken>, amount: u64) -> Result<()> {
+        instructions::deposit_token(ctx, amount)
+    }
+
+    /// Withdraw tokens from a token LockBox vault (only once the unlock policy is satisfied)
+    pub fn withdraw_token(ctx: Context<WithdrawToken>, amount: u64) -> Result<()> {
+        instructions::withdraw_token(ctx, amount)
+    }
+
+    /// Emergency withdrawal for token LockBoxes - the penalty goes to the treasury's token account
+    pub fn emergency_withdraw_token(
+        ctx: Context<EmergencyWithdrawToken>,
+        keep_record: bool,
+    ) -> Result<()> {
+        instructions::emergency_withdraw_token(ctx, keep_record)
+    }
+
+    /// Close a token LockBox and its empty token vault, returning rent to the owner
+    pub fn close_token_lockbox(ctx: Context<CloseTokenLockBox>) -> Result<()> {
+        instructions::close_token_lockbox(ctx)
+    }
+
+    /// Create the global Config (upgrade authority only)
+    pub fn initialize_config(ctx: Context<InitializeConfig>, params: ConfigParams) -> Result<()> {
+        instructions::initialize_config(ctx, params)
+    }
+
+    /// Update the global Config parameters (admin only)
+    pub fn update_config(ctx: Context<UpdateConfig>, params: ConfigParams) -> Result<()> {
+        instructions::update_config(ctx, params)
+    }
+
+    /// Hand the Config admin role to a new authority (both must sign)
+    pub fn transfer_admin(ctx: Context<TransferAdmin>) -> Result<()> {
+        instructions::transfer_admin(ctx)
+    }
 }
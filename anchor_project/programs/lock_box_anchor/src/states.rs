@@ -1,19 +1,902 @@
+use crate::errors::LockBoxError;
 use anchor_lang::prelude::*;
 
 // Seed constants for PDAs
 pub const LOCKBOX_SEED: &[u8] = b"lockbox";
 pub const VAULT_SEED: &[u8] = b"vault";
+pub const CONFIG_SEED: &[u8] = b"config";
+pub const CONTRIBUTION_SEED: &[u8] = b"contribution";
+pub const PROPOSAL_SEED: &[u8] = b"proposal";
+
+// Basis points used for penalties (10_000 bps = 100%)
+pub const BPS_DENOMINATOR: u64 = 10_000;
+pub const MAX_PENALTY_BPS: u16 = 5_000;
+
+// Guardians that can be registered on one LockBox (one optional signer slot each)
+pub const MAX_GUARDIANS: usize = 3;
+
+// Size of the signer set of a multisig LockBox
+pub const MAX_SIGNERS: usize = 5;
+
+// Partial unlock steps one LockBox can have
+pub const MAX_MILESTONES: usize = 4;
+
+// Minimum time between a target change and lowering the target for free
+pub const TARGET_DECREASE_COOLDOWN: i64 = 7 * 24 * 60 * 60;
+
+/// The SOL vault is a zero-data system account, so it must hold either
+/// nothing or at least this many lamports.
+pub fn vault_rent_minimum() -> Result<u64> {
+    Ok(Rent::get()?.minimum_balance(0))
+}
+
+/// Admin-tunable program parameters, stored on the global Config PDA
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct ConfigParams {
+    pub treasury: Pubkey,           // receives early-exit penalties
+    pub min_penalty_bps: u16,       // lowest max_bps a LockBox may choose
+    pub max_penalty_bps: u16,       // highest max_bps a LockBox may choose
+    pub min_target_amount: u64,     // smallest allowed target in lamports
+    pub max_target_amount: u64,     // largest allowed target in lamports
+    pub paused: bool,               // global pause for every LockBox instruction
+    pub min_inactivity_period: i64, // shortest inactivity period an heir can be set up with
+}
+
+impl ConfigParams {
+    pub const LEN: usize = 32 + 2 + 2 + 8 + 8 + 1 + 8;
+
+    pub fn validate(&self) -> Result<()> {
+        require!(
+            self.min_penalty_bps <= self.max_penalty_bps && self.max_penalty_bps <= MAX_PENALTY_BPS,
+            LockBoxError::InvalidConfig
+        );
+        require!(
+            self.min_target_amount <= self.max_target_amount,
+            LockBoxError::InvalidConfig
+        );
+        require!(
+            self.treasury != Pubkey::default(),
+            LockBoxError::InvalidConfig
+        );
+        require!(self.min_inactivity_period > 0, LockBoxError::InvalidConfig);
+
+        Ok(())
+    }
+}
+
+#[account]
+pub struct Config {
+    pub admin: Pubkey,        // 32 bytes - authority allowed to update the config
+    pub params: ConfigParams, // 61 bytes - program parameters
+    pub lockbox_count: u64,   // 8 bytes - LockBoxes created so far
+    pub bump: u8,             // 1 byte - PDA bump seed
+}
+
+impl Config {
+    pub const LEN: usize = 32 + ConfigParams::LEN + 8 + 1 + 8; // discriminator + fields
+
+    /// Hands out a nonce no other LockBox had, even one created earlier at the same address
+    pub fn next_lockbox_nonce(&mut self) -> u64 {
+        let nonce = self.lockbox_count;
+        self.lockbox_count = self.lockbox_count.checked_add(1).unwrap();
+        nonce
+    }
+
+    pub fn require_not_paused(&self) -> Result<()> {
+        require!(!self.params.paused, LockBoxError::ProgramPaused);
+        Ok(())
+    }
+}
+
+/// Which conditions must hold before `withdraw` is allowed
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum UnlockPolicy {
+    TargetOnly,    // unlock once current_balance reaches target_amount
+    TimeOnly,      // unlock once unlock_at has passed (term deposit)
+    TargetOrTime,  // unlock on whichever comes first
+    TargetAndTime, // unlock only when both hold
+}
+
+/// Lifecycle of a LockBox. Every instruction validates its transition.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum LockBoxStatus {
+    Active,    // saving, withdrawals locked
+    Unlocked,  // unlock policy satisfied, withdrawals allowed
+    Paused,    // frozen, every instruction is rejected
+    Broken,    // emptied by emergency withdrawal, kept as an inactive record
+    Completed, // unlocked and fully withdrawn, reopened by sync_balance if lamports arrive
+    Closed,    // account closed, only observable in the closing transaction
+}
+
+impl LockBoxStatus {
+    pub fn can_transition_to(self, next: LockBoxStatus) -> bool {
+        use LockBoxStatus::*;
+
+        matches!(
+            (self, next),
+            (Active, Unlocked | Paused | Broken | Closed)
+                | (Unlocked, Completed | Paused | Broken | Closed)
+                | (Paused, Active | Unlocked)
+                | (Completed, Unlocked | Closed)
+        )
+    }
+}
+
+/// Early-exit penalty that decays linearly with time elapsed since creation
+/// and with progress toward the target.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct PenaltySchedule {
+    pub max_bps: u16,        // penalty on day one with nothing saved
+    pub decay_duration: i64, // seconds until the penalty reaches zero (0 = no time decay)
+}
+
+impl PenaltySchedule {
+    pub const LEN: usize = 2 + 8;
+}
+
+/// Partial unlock step: once the balance reaches `threshold_bps` of the target,
+/// `unlock_bps` of the balance at that moment can be withdrawn before the full unlock.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Milestone {
+    pub threshold_bps: u16, // progress toward the target that reaches it
+    pub unlock_bps: u16,    // share of the balance it releases
+}
+
+impl Milestone {
+    pub const LEN: usize = 2 + 2;
+}
+
+/// Creation parameters shared by SOL and token LockBoxes
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug)]
+pub struct LockBoxParams {
+    pub target_amount: u64,                // goal in lamports or token base units
+    pub unlock_at: Option<i64>,            // optional timestamp for time-based unlock
+    pub unlock_policy: UnlockPolicy,       // how target and unlock_at combine
+    pub penalty_schedule: PenaltySchedule, // emergency withdrawal penalty schedule
+    pub crowdfund_deadline: Option<i64>,   // set for all-or-nothing crowdfund LockBoxes
+    pub beneficiary: Option<Pubkey>,       // receives withdrawals instead of the owner
+    pub vesting_duration: i64,             // linear release period after unlock, 0 = at once
+    pub milestones: Vec<Milestone>,        // partial unlocks in ascending order of threshold
+}
+
+impl LockBoxParams {
+    /// Checks the parameters against the program config at creation time.
+    /// The lamport target range is checked by the SOL instruction only.
+    pub fn validate(&self, config: &Config, now: i64) -> Result<()> {
+        require!(self.target_amount > 0, LockBoxError::InvalidTargetAmount);
+        require!(
+            self.penalty_schedule.max_bps >= config.params.min_penalty_bps
+                && self.penalty_schedule.max_bps <= config.params.max_penalty_bps,
+            LockBoxError::InvalidPenaltyBps
+        );
+        require!(
+            self.penalty_schedule.decay_duration >= 0,
+            LockBoxError::InvalidPenaltySchedule
+        );
+        require!(
+            self.vesting_duration >= 0,
+            LockBoxError::InvalidVestingDuration
+        );
+
+        // Time-based policies need an unlock time in the future, target-only must not have one
+        match (self.unlock_policy, self.unlock_at) {
+            (UnlockPolicy::TargetOnly, None) => {}
+            (UnlockPolicy::TargetOnly, Some(_)) | (_, None) => {
+                return err!(LockBoxError::InvalidUnlockPolicy);
+            }
+            (_, Some(unlock_at)) => {
+                require!(unlock_at > now, LockBoxError::InvalidUnlockTime);
+            }
+        }
+
+        // Crowdfunds are all-or-nothing on the target alone
+        if let Some(deadline) = self.crowdfund_deadline {
+            require!(
+                deadline > now && self.unlock_policy == UnlockPolicy::TargetOnly,
+                LockBoxError::InvalidCrowdfundDeadline
+            );
+        }
+
+        // Milestones come before the target, each one further along than the last
+        let ascending = self
+            .milestones
+            .windows(2)
+            .all(|pair| pair[0].threshold_bps < pair[1].threshold_bps);
+        require!(
+            self.milestones.len() <= MAX_MILESTONES
+                && ascending
+                && self.milestones.iter().all(|milestone| {
+                    milestone.threshold_bps > 0
+                        && (milestone.threshold_bps as u64) < BPS_DENOMINATOR
+                        && milestone.unlock_bps > 0
+                        && milestone.unlock_bps as u64 <= BPS_DENOMINATOR
+                })
+                && (self.milestones.is_empty() || self.crowdfund_deadline.is_none()),
+            LockBoxError::InvalidMilestones
+        );
+
+        Ok(())
+    }
+}
 
 #[account]
 pub struct LockBox {
-    pub owner: Pubkey,            // 32 bytes
-    pub target_amount: u64,       // 8 bytes - goal amount in lamports
-    pub current_balance: u64,     // 8 bytes - current balance in lamports
-    pub created_at: i64,          // 8 bytes - timestamp when created
-    pub has_reached_target: bool, // 1 byte - whether target has been reached
-    pub bump: u8,                 // 1 byte - PDA bump seed
+    pub owner: Pubkey,   // 32 bytes - current owner, changes with accept_owner
+    pub creator: Pubkey, // 32 bytes - original owner, part of the PDA seeds
+    pub pending_owner: Option<Pubkey>, // 33 bytes - proposed new owner awaiting acceptance
+    pub beneficiary: Option<Pubkey>, // 33 bytes - receives withdrawals instead of the owner
+    pub id: u64,         // 8 bytes - creator-chosen id, part of the PDA seeds
+    pub nonce: u64,      // 8 bytes - unique per LockBox, the PDA is reused after closing
+    pub mint: Option<Pubkey>, // 33 bytes - SPL mint of the vault, None for SOL
+    pub target_amount: u64, // 8 bytes - goal in lamports or token units
+    pub highest_target: u64, // 8 bytes - highest target ever set, measures penalty progress
+    pub current_balance: u64, // 8 bytes - current balance in the same unit
+    pub created_at: i64, // 8 bytes - timestamp when created
+    pub target_updated_at: i64, // 8 bytes - last target change (cooldown start)
+    pub unlock_at: Option<i64>, // 9 bytes - optional timestamp for time-based unlock
+    pub unlock_policy: UnlockPolicy, // 1 byte - how target and unlock_at combine
+    pub penalty_schedule: PenaltySchedule, // 10 bytes - emergency withdrawal penalty schedule
+    pub crowdfund_deadline: Option<i64>, // 9 bytes - set for all-or-nothing crowdfund LockBoxes
+    pub guardians: Vec<Pubkey>, // 4 + 32 * MAX_GUARDIANS bytes - emergency co-signers
+    pub guardian_threshold: u8, // 1 byte - guardian signatures an emergency needs
+    pub signers: Vec<Pubkey>, // 4 + 32 * MAX_SIGNERS bytes - multisig signer set
+    pub signer_threshold: u8, // 1 byte - approvals a proposal needs
+    pub proposal_count: u64, // 8 bytes - id of the next proposal
+    pub heir: Option<Pubkey>, // 33 bytes - takes over after the inactivity period
+    pub inactivity_period: i64, // 8 bytes - seconds of inactivity before a claim
+    pub last_activity_at: i64, // 8 bytes - last owner instruction
+    pub vesting_duration: i64, // 8 bytes - linear release period after unlock, 0 = none
+    pub unlocked_at: Option<i64>, // 9 bytes - start of the vesting period
+    pub withdrawn_since_unlock: u64, // 8 bytes - already released from the vested amount
+    pub withdraw_limit: u64, // 8 bytes - max withdrawn per window, 0 = unlimited
+    pub withdraw_window: i64, // 8 bytes - rolling window length in seconds
+    pub window_updated_at: i64, // 8 bytes - when window_used was last updated
+    pub window_used: u64, // 8 bytes - allowance in use, frees up over the window
+    pub milestones: Vec<Milestone>, // 4 + 4 * MAX_MILESTONES bytes - partial unlock steps
+    pub milestones_reached: u8, // 1 byte - reached milestones, always a prefix of the list
+    pub milestone_allowance: u64, // 8 bytes - released by milestones, not withdrawn yet
+    pub broken_at: Option<i64>, // 9 bytes - time of the emergency withdrawal
+    pub emergency_withdrawn: u64, // 8 bytes - paid out by the emergency withdrawal
+    pub emergency_penalty: u64, // 8 bytes - penalty paid by the emergency withdrawal
+    pub status_before_pause: LockBoxStatus, // 1 byte - restored when the pause ends
+    pub paused_until: Option<i64>, // 9 bytes - optional automatic end of the pause
+    pub status: LockBoxStatus, // 1 byte - lifecycle state
+    pub bump: u8,        // 1 byte - PDA bump seed
 }
 
 impl LockBox {
-    pub const LEN: usize = 32 + 8 + 8 + 8 + 1 + 1 + 8; // discriminator + fields
+    pub const LEN: usize = 32 // owner
+        + 32 // creator
+        + 33 // pending_owner
+        + 33 // beneficiary
+        + 8 // id
+        + 8 // nonce
+        + 33 // mint
+        + 8 // target_amount
+        + 8 // highest_target
+        + 8 // current_balance
+        + 8 // created_at
+        + 8 // target_updated_at
+        + 9 // unlock_at
+        + 1 // unlock_policy
+        + PenaltySchedule::LEN // penalty_schedule
+        + 9 // crowdfund_deadline
+        + (4 + 32 * MAX_GUARDIANS) // guardians
+        + 1 // guardian_threshold
+        + (4 + 32 * MAX_SIGNERS) // signers
+        + 1 // signer_threshold
+        + 8 // proposal_count
+        + 33 // heir
+        + 8 // inactivity_period
+        + 8 // last_activity_at
+        + 8 // vesting_duration
+        + 9 // unlocked_at
+        + 8 // withdrawn_since_unlock
+        + 8 // withdraw_limit
+        + 8 // withdraw_window
+        + 8 // window_updated_at
+        + 8 // window_used
+        + (4 + Milestone::LEN * MAX_MILESTONES) // milestones
+        + 1 // milestones_reached
+        + 8 // milestone_allowance
+        + 9 // broken_at
+        + 8 // emergency_withdrawn
+        + 8 // emergency_penalty
+        + 1 // status_before_pause
+        + 9 // paused_until
+        + 1 // status
+        + 1 // bump
+        + 8; // discriminator
+
+    /// Fills in a freshly created LockBox from already validated parameters
+    pub fn init(
+        &mut self,
+        owner: Pubkey,
+        id: u64,
+        mint: Option<Pubkey>,
+        params: &LockBoxParams,
+        bump: u8,
+        now: i64,
+    ) {
+        self.owner = owner;
+        self.creator = owner;
+        self.pending_owner = None;
+        self.beneficiary = params.beneficiary;
+        self.id = id;
+        self.mint = mint;
+        self.target_amount = params.target_amount;
+        self.highest_target = params.target_amount;
+        self.current_balance = 0;
+        self.created_at = now;
+        self.target_updated_at = now;
+        self.unlock_at = params.unlock_at;
+        self.unlock_policy = params.unlock_policy;
+        self.penalty_schedule = params.penalty_schedule;
+
+        // Without an explicit decay period the penalty reaches zero at unlock_at
+        if params.penalty_schedule.decay_duration == 0 {
+            if let Some(unlock_at) = params.unlock_at {
+                self.penalty_schedule.decay_duration = unlock_at - now;
+            }
+        }
+
+        self.crowdfund_deadline = params.crowdfund_deadline;
+        self.guardians = Vec::new();
+        self.guardian_threshold = 0;
+        self.signers = Vec::new();
+        self.signer_threshold = 0;
+        self.proposal_count = 0;
+        self.heir = None;
+        self.inactivity_period = 0;
+        self.last_activity_at = now;
+        self.vesting_duration = params.vesting_duration;
+        self.unlocked_at = None;
+        self.withdrawn_since_unlock = 0;
+        self.withdraw_limit = 0;
+        self.withdraw_window = 0;
+        self.window_updated_at = 0;
+        self.window_used = 0;
+        self.milestones = params.milestones.clone();
+        self.milestones_reached = 0;
+        self.milestone_allowance = 0;
+        self.broken_at = None;
+        self.emergency_withdrawn = 0;
+        self.emergency_penalty = 0;
+        self.status_before_pause = LockBoxStatus::Active;
+        self.paused_until = None;
+        self.status = LockBoxStatus::Active;
+        self.bump = bump;
+    }
+
+    /// Replaces the guardian set. An empty set with threshold 0 removes the requirement.
+    pub fn set_guardians(&mut self, guardians: Vec<Pubkey>, threshold: u8) -> Result<()> {
+        let unique = guardians
+            .iter()
+            .enumerate()
+            .all(|(i, guardian)| !guardians[..i].contains(guardian));
+
+        require!(
+            guardians.len() <= MAX_GUARDIANS
+                && unique
+                && !guardians.contains(&self.owner)
+                && threshold as usize <= guardians.len()
+                && (threshold > 0 || guardians.is_empty()),
+            LockBoxError::InvalidGuardians
+        );
+
+        self.guardians = guardians;
+        self.guardian_threshold = threshold;
+        Ok(())
+    }
+
+    /// Whether enough registered guardians are among the co-signers (always true without guardians)
+    pub fn has_guardian_approval(&self, signers: &[Option<&Signer>]) -> bool {
+        let approvals = self
+            .guardians
+            .iter()
+            .filter(|guardian| signers.iter().flatten().any(|s| s.key() == **guardian))
+            .count();
+
+        approvals >= self.guardian_threshold as usize
+    }
+
+    /// Multisig LockBoxes only move funds through approved proposals
+    pub fn is_multisig(&self) -> bool {
+        !self.signers.is_empty()
+    }
+
+    /// Hands withdrawals and closing over to an m-of-n signer set
+    pub fn set_signers(&mut self, signers: Vec<Pubkey>, threshold: u8) -> Result<()> {
+        let unique = signers
+            .iter()
+            .enumerate()
+            .all(|(i, signer)| !signers[..i].contains(signer));
+
+        require!(
+            !signers.is_empty()
+                && signers.len() <= MAX_SIGNERS
+                && unique
+                && threshold > 0
+                && threshold as usize <= signers.len(),
+            LockBoxError::InvalidMultisig
+        );
+
+        self.signers = signers;
+        self.signer_threshold = threshold;
+        Ok(())
+    }
+
+    /// Sets or removes (`None`) the heir of the dead-man's switch. The period
+    /// must be at least the config's `min_inactivity_period`.
+    pub fn set_heir(
+        &mut self,
+        heir: Option<Pubkey>,
+        inactivity_period: i64,
+        min_inactivity_period: i64,
+    ) -> Result<()> {
+        match heir {
+            Some(heir) => {
+                require!(
+                    heir != self.owner && inactivity_period >= min_inactivity_period,
+                    LockBoxError::InvalidInheritance
+                );
+                self.inactivity_period = inactivity_period;
+            }
+            None => self.inactivity_period = 0,
+        }
+
+        self.heir = heir;
+        Ok(())
+    }
+
+    /// Records owner activity, which restarts the inheritance countdown
+    pub fn touch(&mut self, now: i64) {
+        self.last_activity_at = now;
+    }
+
+    /// Whether the heir may take over at `now`
+    pub fn inheritance_claimable(&self, now: i64) -> bool {
+        self.heir.is_some() && now >= self.last_activity_at.saturating_add(self.inactivity_period)
+    }
+
+    /// Sets the spending budget (`limit` per `window` seconds, 0/0 removes it).
+    /// Once unlocked the budget can only be tightened.
+    pub fn set_withdrawal_limit(&mut self, limit: u64, window: i64) -> Result<()> {
+        let valid = if limit == 0 { window == 0 } else { window > 0 };
+        let loosens = self.withdraw_limit != 0
+            && (limit == 0 || limit > self.withdraw_limit || window < self.withdraw_window);
+
+        require!(
+            valid && (self.status == LockBoxStatus::Active || !loosens),
+            LockBoxError::InvalidWithdrawalLimit
+        );
+
+        self.withdraw_limit = limit;
+        self.withdraw_window = window;
+        Ok(())
+    }
+
+    /// Counts `amount` against the rolling withdrawal window. Spent allowance frees up
+    /// gradually, at `withdraw_limit / withdraw_window` per second, instead of all at once
+    /// at a window boundary where a second full limit could follow right after the first.
+    fn consume_rolling_allowance(&mut self, amount: u64, now: i64) -> Result<()> {
+        if self.withdraw_limit == 0 {
+            return Ok(());
+        }
+
+        let elapsed = now.saturating_sub(self.window_updated_at).max(0) as u128;
+        let freed = (self.withdraw_limit as u128 * elapsed / self.withdraw_window as u128)
+            .min(u64::MAX as u128) as u64;
+        self.window_used = self.window_used.saturating_sub(freed);
+        self.window_updated_at = now;
+
+        let used = self.window_used.saturating_add(amount);
+        if used > self.withdraw_limit {
+            if amount > self.withdraw_limit {
+                msg!(
+                    "Withdrawal limit: {} is more than the limit of {}",
+                    amount,
+                    self.withdraw_limit
+                );
+            } else {
+                // Seconds until enough of the allowance has freed up, rounded up
+                let wait = ((used - self.withdraw_limit) as u128 * self.withdraw_window as u128)
+                    .div_ceil(self.withdraw_limit as u128);
+                msg!(
+                    "Withdrawal limit: {} of {} in use, enough frees up at {}",
+                    self.window_used,
+                    self.withdraw_limit,
+                    now.saturating_add(wait.min(i64::MAX as u128) as i64)
+                );
+            }
+            return err!(LockBoxError::WithdrawalLimitExceeded);
+        }
+
+        self.window_used = used;
+        Ok(())
+    }
+
+    /// An unlocked LockBox pays no early-exit penalty, so an emergency withdrawal
+    /// there would only skip the vesting and the withdrawal limit that `withdraw` enforces.
+    /// A LockBox whose unlock time has passed counts as unlocked even while still `Active`.
+    pub fn require_emergency_exit_allowed(&self, now: i64) -> Result<()> {
+        let unlocked = self.status == LockBoxStatus::Unlocked
+            || (self.status == LockBoxStatus::Active && self.check_unlocked(now).is_ok());
+        require!(
+            !unlocked || (self.vesting_duration == 0 && self.withdraw_limit == 0),
+            LockBoxError::EmergencyExitRestricted
+        );
+        Ok(())
+    }
+
+    /// Keeps the outcome of an emergency withdrawal on the Broken record
+    pub fn record_emergency_withdrawal(&mut self, withdrawn: u64, penalty: u64, now: i64) {
+        self.broken_at = Some(now);
+        self.emergency_withdrawn = withdrawn;
+        self.emergency_penalty = penalty;
+        self.current_balance = 0;
+    }
+
+    /// Wallet that withdrawals are paid to
+    pub fn payout_key(&self) -> Pubkey {
+        self.beneficiary.unwrap_or(self.owner)
+    }
+
+    pub fn is_token(&self) -> bool {
+        self.mint.is_some()
+    }
+
+    /// Current early-exit penalty in bps: `max_bps` scaled by the share of the
+    /// decay period still left and, while the target is what keeps the LockBox
+    /// locked, by the share of the highest target ever set still missing.
+    /// Lowering the target therefore never makes breaking the lock cheaper.
+    pub fn early_exit_penalty_bps(&self, now: i64) -> u16 {
+        let schedule = &self.penalty_schedule;
+        if schedule.max_bps == 0 || self.highest_target == 0 {
+            return 0;
+        }
+
+        let (time_left, duration) = if schedule.decay_duration > 0 {
+            let elapsed = now.saturating_sub(self.created_at).max(0);
+            (
+                schedule.decay_duration.saturating_sub(elapsed).max(0) as u128,
+                schedule.decay_duration as u128,
+            )
+        } else {
+            (1, 1)
+        };
+
+        // A term deposit stays locked however much is saved, so progress only counts toward the target
+        let target_blocks = match self.unlock_policy {
+            UnlockPolicy::TimeOnly => false,
+            UnlockPolicy::TargetAndTime => self.unlock_at.is_some_and(|unlock_at| now >= unlock_at),
+            UnlockPolicy::TargetOnly | UnlockPolicy::TargetOrTime => true,
+        };
+        let (missing, target) = if target_blocks {
+            (
+                self.highest_target.saturating_sub(self.current_balance) as u128,
+                self.highest_target as u128,
+            )
+        } else {
+            (1, 1)
+        };
+
+        (schedule.max_bps as u128 * time_left * missing / (duration * target)) as u16
+    }
+
+    /// Penalty taken from `amount` when the lock is broken at `now`
+    pub fn early_exit_penalty(&self, amount: u64, now: i64) -> u64 {
+        let bps = self.early_exit_penalty_bps(now);
+        (amount as u128 * bps as u128 / BPS_DENOMINATOR as u128) as u64
+    }
+
+    pub fn is_crowdfund(&self) -> bool {
+        self.crowdfund_deadline.is_some()
+    }
+
+    /// A crowdfund fails when its deadline passes before the target unlocked it
+    pub fn crowdfund_failed(&self, now: i64) -> bool {
+        self.crowdfund_deadline
+            .is_some_and(|deadline| now >= deadline && self.status == LockBoxStatus::Active)
+    }
+
+    pub fn has_reached_target(&self) -> bool {
+        self.current_balance >= self.target_amount
+    }
+
+    /// Fails unless the LockBox accepts deposits and withdrawals
+    pub fn require_operational(&self) -> Result<()> {
+        match self.status {
+            LockBoxStatus::Active | LockBoxStatus::Unlocked => Ok(()),
+            LockBoxStatus::Paused => err!(LockBoxError::LockBoxPaused),
+            _ => err!(LockBoxError::VaultInactive),
+        }
+    }
+
+    /// Freezes the LockBox until it is resumed or `paused_until` has passed
+    pub fn pause(&mut self, paused_until: Option<i64>, now: i64) -> Result<()> {
+        // Pausing would let the owner hold contributors' refunds hostage
+        require!(!self.is_crowdfund(), LockBoxError::CrowdfundRestricted);
+        require!(
+            paused_until.unwrap_or(i64::MAX) > now,
+            LockBoxError::InvalidPauseEnd
+        );
+
+        self.status_before_pause = self.status;
+        self.paused_until = paused_until;
+        self.transition_to(LockBoxStatus::Paused)
+    }
+
+    /// Returns to the status the LockBox had before it was paused
+    pub fn resume(&mut self) -> Result<()> {
+        self.paused_until = None;
+        self.transition_to(self.status_before_pause)
+    }
+
+    /// Fails unless the LockBox is paused; inactive records can't be resumed
+    pub fn require_paused(&self) -> Result<()> {
+        match self.status {
+            LockBoxStatus::Paused => Ok(()),
+            LockBoxStatus::Broken | LockBoxStatus::Completed | LockBoxStatus::Closed => {
+                err!(LockBoxError::VaultInactive)
+            }
+            _ => err!(LockBoxError::InvalidStatusTransition),
+        }
+    }
+
+    pub fn pause_expired(&self, now: i64) -> bool {
+        self.paused_until.is_some_and(|until| now >= until)
+    }
+
+    /// Fails while the LockBox is paused. A pause whose `paused_until` has
+    /// passed ends here, so no separate resume is needed.
+    pub fn require_not_paused(&mut self, now: i64) -> Result<()> {
+        if self.status == LockBoxStatus::Paused {
+            require!(self.pause_expired(now), LockBoxError::LockBoxPaused);
+            self.resume()?;
+        }
+
+        Ok(())
+    }
+
+    /// Moves to `next`, rejecting transitions the lifecycle does not allow
+    pub fn transition_to(&mut self, next: LockBoxStatus) -> Result<()> {
+        if !self.status.can_transition_to(next) {
+            return match self.status {
+                LockBoxStatus::Paused => err!(LockBoxError::LockBoxPaused),
+                LockBoxStatus::Broken | LockBoxStatus::Completed | LockBoxStatus::Closed => {
+                    err!(LockBoxError::VaultInactive)
+                }
+                _ => err!(LockBoxError::InvalidStatusTransition),
+            };
+        }
+
+        msg!("LockBox status: {:?} -> {:?}", self.status, next);
+        self.status = next;
+        Ok(())
+    }
+
+    /// Moves an Active LockBox to Unlocked once its unlock policy holds.
+    /// Returns whether the LockBox is unlocked afterwards.
+    pub fn try_unlock(&mut self, now: i64) -> Result<bool> {
+        if self.status == LockBoxStatus::Active && self.check_unlocked(now).is_ok() {
+            self.unlock(now)?;
+        }
+
+        Ok(self.status == LockBoxStatus::Unlocked)
+    }
+
+    /// Called after the balance grows or the target changes: unlocks the
+    /// LockBox if reaching the target satisfies its unlock policy.
+    pub fn check_target_reached(&mut self, now: i64) -> Result<()> {
+        self.check_milestones();
+
+        if self.has_reached_target() {
+            if self.try_unlock(now)? {
+                msg!("🎉 Target reached! Withdrawals are now unlocked.");
+            } else {
+                msg!("🎉 Target reached! Funds stay locked until the unlock time.");
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Releases the share of every milestone the balance has crossed while still locked
+    fn check_milestones(&mut self) {
+        if self.status != LockBoxStatus::Active {
+            return;
+        }
+
+        while let Some(milestone) = self.milestones.get(self.milestones_reached as usize) {
+            let progress_bps =
+                self.current_balance as u128 * BPS_DENOMINATOR as u128 / self.target_amount as u128;
+            if progress_bps < milestone.threshold_bps as u128 {
+                break;
+            }
+
+            let released = (self.current_balance as u128 * milestone.unlock_bps as u128
+                / BPS_DENOMINATOR as u128) as u64;
+            self.milestone_allowance = self.milestone_allowance.saturating_add(released);
+            self.milestones_reached += 1;
+
+            msg!(
+                "🎯 Milestone {} reached ({} bps), {} released for withdrawal",
+                self.milestones_reached,
+                milestone.threshold_bps,
+                released
+            );
+        }
+    }
+
+    /// Checks a withdrawal of `amount` against the unlock policy and the
+    /// balance, then debits it. Completes the LockBox once it is emptied.
+    pub fn apply_withdrawal(&mut self, amount: u64, now: i64) -> Result<()> {
+        self.require_operational()?;
+
+        // Check the unlock policy (target and/or unlock time) on the first withdrawal
+        if self.status == LockBoxStatus::Active {
+            if let Err(locked) = self.check_unlocked(now) {
+                // Still locked, but reached milestones may have released part of the balance
+                if amount == 0 || amount > self.milestone_allowance {
+                    return Err(locked);
+                }
+                require!(
+                    self.current_balance >= amount,
+                    LockBoxError::InsufficientBalance
+                );
+
+                self.consume_rolling_allowance(amount, now)?;
+                self.milestone_allowance -= amount;
+                self.current_balance -= amount;
+                return Ok(());
+            }
+            self.unlock(now)?;
+        }
+
+        require!(
+            self.current_balance >= amount,
+            LockBoxError::InsufficientBalance
+        );
+
+        let vested = self.vested_amount(now);
+        if amount > vested {
+            msg!("Only {} vested so far", vested);
+            return err!(LockBoxError::AmountNotVested);
+        }
+
+        self.consume_rolling_allowance(amount, now)?;
+
+        self.current_balance -= amount;
+        self.withdrawn_since_unlock += amount;
+
+        // An unlocked LockBox that has been fully withdrawn is done
+        if self.current_balance == 0 {
+            self.transition_to(LockBoxStatus::Completed)?;
+        }
+
+        Ok(())
+    }
+
+    /// Moves to Unlocked and starts the vesting period. A time-based unlock is
+    /// only noticed by the next instruction, so it counts from `unlock_at`.
+    fn unlock(&mut self, now: i64) -> Result<()> {
+        self.transition_to(LockBoxStatus::Unlocked)?;
+
+        let unlocked_by_time = match self.unlock_policy {
+            UnlockPolicy::TimeOnly => true,
+            UnlockPolicy::TargetOrTime => !self.has_reached_target(),
+            UnlockPolicy::TargetOnly | UnlockPolicy::TargetAndTime => false,
+        };
+        self.unlocked_at = Some(match self.unlock_at {
+            Some(unlock_at) if unlocked_by_time => unlock_at,
+            _ => now,
+        });
+
+        Ok(())
+    }
+
+    /// Part of the balance that may be withdrawn at `now`. With vesting, everything
+    /// that was in the LockBox since unlock releases linearly over `vesting_duration`.
+    pub fn vested_amount(&self, now: i64) -> u64 {
+        let Some(unlocked_at) = self.unlocked_at else {
+            return 0;
+        };
+        if self.vesting_duration == 0 {
+            return self.current_balance;
+        }
+
+        let total = self.current_balance as u128 + self.withdrawn_since_unlock as u128;
+        let elapsed = now
+            .saturating_sub(unlocked_at)
+            .clamp(0, self.vesting_duration) as u128;
+        let vested = (total * elapsed / self.vesting_duration as u128) as u64;
+
+        vested
+            .saturating_sub(self.withdrawn_since_unlock)
+            .min(self.current_balance)
+    }
+
+    /// Checks the unlock policy against the current time, failing with the
+    /// error for whichever condition is still missing.
+    pub fn check_unlocked(&self, now: i64) -> Result<()> {
+        self.check_unlocked_with_target(self.target_amount, now)
+    }
+
+    /// Same check as `check_unlocked`, as if the target were `target_amount`
+    pub fn check_unlocked_with_target(&self, target_amount: u64, now: i64) -> Result<()> {
+        let target_met = self.current_balance >= target_amount;
+        let time_met = self.unlock_at.is_some_and(|unlock_at| now >= unlock_at);
+
+        match self.unlock_policy {
+            UnlockPolicy::TargetOnly => {
+                require!(target_met, LockBoxError::TargetNotReached);
+            }
+            UnlockPolicy::TimeOnly => {
+                require!(time_met, LockBoxError::UnlockTimeNotReached);
+            }
+            UnlockPolicy::TargetOrTime => {
+                require!(target_met || time_met, LockBoxError::TargetNotReached);
+            }
+            UnlockPolicy::TargetAndTime => {
+                require!(target_met, LockBoxError::TargetNotReached);
+                require!(time_met, LockBoxError::UnlockTimeNotReached);
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Receipt of everything one contributor has paid into a LockBox
+#[account]
+pub struct Contribution {
+    pub lockbox: Pubkey,            // 32 bytes
+    pub lockbox_nonce: u64,         // 8 bytes - nonce of the LockBox the receipt was created for
+    pub contributor: Pubkey,        // 32 bytes
+    pub total_amount: u64,          // 8 bytes - lamports contributed so far
+    pub first_contribution_at: i64, // 8 bytes
+    pub last_contribution_at: i64,  // 8 bytes
+    pub count: u32,                 // 4 bytes - number of contributions
+    pub bump: u8,                   // 1 byte - PDA bump seed
+}
+
+impl Contribution {
+    pub const LEN: usize = 32 + 8 + 32 + 8 + 8 + 8 + 4 + 1 + 8; // discriminator + fields
+}
+
+/// What a multisig proposal does once enough signers approved it
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ProposalAction {
+    Withdraw { amount: u64 },            // pay out to the owner or beneficiary
+    Close,                               // close the empty LockBox, rent goes to the owner
+    Pause { paused_until: Option<i64> }, // freeze the LockBox, see `pause_lockbox`
+    Resume,                              // end a pause, the only action a paused LockBox accepts
+}
+
+impl ProposalAction {
+    pub const LEN: usize = 1 + 9; // variant + largest payload
+}
+
+/// Pending multisig action, closed back to the proposer once executed
+#[account]
+pub struct Proposal {
+    pub lockbox: Pubkey,        // 32 bytes
+    pub id: u64,                // 8 bytes - part of the PDA seeds
+    pub proposer: Pubkey,       // 32 bytes - paid the rent and gets it back
+    pub action: ProposalAction, // 10 bytes
+    pub approvals: Vec<Pubkey>, // 4 + 32 * MAX_SIGNERS bytes
+    pub created_at: i64,        // 8 bytes
+    pub bump: u8,               // 1 byte - PDA bump seed
+}
+
+impl Proposal {
+    pub const LEN: usize = 32 // lockbox
+        + 8 // id
+        + 32 // proposer
+        + ProposalAction::LEN // action
+        + (4 + 32 * MAX_SIGNERS) // approvals
+        + 8 // created_at
+        + 1 // bump
+        + 8; // discriminator
 }
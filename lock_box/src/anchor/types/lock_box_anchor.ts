@@ -14,9 +14,9 @@ export type LockBoxAnchor = {
   }
   instructions: [
     {
-      name: 'closeLockbox'
-      docs: ['Close the LockBox account and reclaim rent (vault must be empty)']
-      discriminator: [249, 5, 46, 49, 123, 1, 211, 26]
+      name: 'acceptOwner'
+      docs: ['Accept a pending ownership transfer (signed by the proposed owner)']
+      discriminator: [176, 23, 41, 28, 23, 111, 8, 4]
       accounts: [
         {
           name: 'lockbox'
@@ -29,48 +29,56 @@ export type LockBoxAnchor = {
               },
               {
                 kind: 'account'
-                path: 'owner'
+                path: 'lockbox.creator'
+                account: 'lockBox'
+              },
+              {
+                kind: 'account'
+                path: 'lockbox.id'
+                account: 'lockBox'
               },
             ]
           }
         },
         {
-          name: 'owner'
-          writable: true
+          name: 'newOwner'
           signer: true
-          relations: ['lockbox']
         },
         {
-          name: 'vault'
-          writable: true
+          name: 'config'
           pda: {
             seeds: [
               {
                 kind: 'const'
-                value: [118, 97, 117, 108, 116]
+                value: [99, 111, 110, 102, 105, 103]
               },
+            ]
+          }
+        },
+        {
+          name: 'eventAuthority'
+          pda: {
+            seeds: [
               {
-                kind: 'account'
-                path: 'lockbox'
+                kind: 'const'
+                value: [95, 95, 101, 118, 101, 110, 116, 95, 97, 117, 116, 104, 111, 114, 105, 116, 121]
               },
             ]
           }
         },
         {
-          name: 'systemProgram'
-          address: '11111111111111111111111111111111'
+          name: 'program'
         },
       ]
       args: []
     },
     {
-      name: 'deposit'
-      docs: ['Deposit SOL into the LockBox vault']
-      discriminator: [242, 35, 198, 137, 82, 225, 242, 182]
+      name: 'approveProposal'
+      docs: ["Add a signer's approval to a pending proposal"]
+      discriminator: [136, 108, 102, 85, 98, 114, 7, 147]
       accounts: [
         {
           name: 'lockbox'
-          writable: true
           pda: {
             seeds: [
               {
@@ -79,49 +87,134 @@ export type LockBoxAnchor = {
               },
               {
                 kind: 'account'
-                path: 'owner'
+                path: 'lockbox.creator'
+                account: 'lockBox'
+              },
+              {
+                kind: 'account'
+                path: 'lockbox.id'
+                account: 'lockBox'
               },
             ]
           }
+          relations: ['proposal']
         },
         {
-          name: 'owner'
+          name: 'proposal'
           writable: true
+          pda: {
+            seeds: [
+              {
+                kind: 'const'
+                value: [112, 114, 111, 112, 111, 115, 97, 108]
+              },
+              {
+                kind: 'account'
+                path: 'lockbox'
+              },
+              {
+                kind: 'account'
+                path: 'proposal.id'
+                account: 'proposal'
+              },
+            ]
+          }
+        },
+        {
+          name: 'signer'
           signer: true
-          relations: ['lockbox']
         },
         {
-          name: 'vault'
+          name: 'config'
+          pda: {
+            seeds: [
+              {
+                kind: 'const'
+                value: [99, 111, 110, 102, 105, 103]
+              },
+            ]
+          }
+        },
+        {
+          name: 'eventAuthority'
+          pda: {
+            seeds: [
+              {
+                kind: 'const'
+                value: [95, 95, 101, 118, 101, 110, 116, 95, 97, 117, 116, 104, 111, 114, 105, 116, 121]
+              },
+            ]
+          }
+        },
+        {
+          name: 'program'
+        },
+      ]
+      args: []
+    },
+    {
+      name: 'claimInheritance'
+      docs: ['Let the heir become the owner after the inactivity period has passed']
+      discriminator: [250, 34, 9, 63, 155, 43, 165, 249]
+      accounts: [
+        {
+          name: 'lockbox'
           writable: true
           pda: {
             seeds: [
               {
                 kind: 'const'
-                value: [118, 97, 117, 108, 116]
+                value: [108, 111, 99, 107, 98, 111, 120]
               },
               {
                 kind: 'account'
-                path: 'lockbox'
+                path: 'lockbox.creator'
+                account: 'lockBox'
+              },
+              {
+                kind: 'account'
+                path: 'lockbox.id'
+                account: 'lockBox'
               },
             ]
           }
         },
         {
-          name: 'systemProgram'
-          address: '11111111111111111111111111111111'
+          name: 'heir'
+          signer: true
         },
-      ]
-      args: [
         {
-          name: 'amount'
-          type: 'u64'
+          name: 'config'
+          pda: {
+            seeds: [
+              {
+                kind: 'const'
+                value: [99, 111, 110, 102, 105, 103]
+              },
+            ]
+          }
+        },
+        {
+          name: 'eventAuthority'
+          pda: {
+            seeds: [
+              {
+                kind: 'const'
+                value: [95, 95, 101, 118, 101, 110, 116, 95, 97, 117, 116, 104, 111, 114, 105, 116, 121]
+              },
+            ]
+          }
+        },
+        {
+          name: 'program'
         },
       ]
+      args: []
     },
     {
-      name: 'emergencyWithdraw'
-      docs: ['Emergency withdrawal - withdraws all funds but deactivates the vault permanently']
-      discriminator: [239, 45, 203, 64, 150, 73, 218, 92]
+      name: 'claimRefund'
+      docs: ["Refund a contributor's recorded total after a crowdfund missed its target by the deadline"]
+      discriminator: [15, 16, 30, 161, 255, 228, 97, 60]
       accounts: [
         {
           name: 'lockbox'
@@ -134,16 +227,43 @@ export type LockBoxAnchor = {
               },
               {
                 kind: 'account'
-                path: 'owner'
+                path: 'lockbox.creator'
+                account: 'lockBox'
+              },
+              {
+                kind: 'account'
+                path: 'lockbox.id'
+                account: 'lockBox'
               },
             ]
           }
+          relations: ['contribution']
         },
         {
-          name: 'owner'
+          name: 'contributor'
           writable: true
           signer: true
-          relations: ['lockbox']
+          relations: ['contribution']
+        },
+        {
+          name: 'contribution'
+          writable: true
+          pda: {
+            seeds: [
+              {
+                kind: 'const'
+                value: [99, 111, 110, 116, 114, 105, 98, 117, 116, 105, 111, 110]
+              },
+              {
+                kind: 'account'
+                path: 'lockbox'
+              },
+              {
+                kind: 'account'
+                path: 'contributor'
+              },
+            ]
+          }
         },
         {
           name: 'vault'
@@ -161,17 +281,94 @@ export type LockBoxAnchor = {
             ]
           }
         },
+        {
+          name: 'config'
+          pda: {
+            seeds: [
+              {
+                kind: 'const'
+                value: [99, 111, 110, 102, 105, 103]
+              },
+            ]
+          }
+        },
         {
           name: 'systemProgram'
           address: '11111111111111111111111111111111'
         },
+        {
+          name: 'eventAuthority'
+          pda: {
+            seeds: [
+              {
+                kind: 'const'
+                value: [95, 95, 101, 118, 101, 110, 116, 95, 97, 117, 116, 104, 111, 114, 105, 116, 121]
+              },
+            ]
+          }
+        },
+        {
+          name: 'program'
+        },
       ]
       args: []
     },
     {
-      name: 'initializeLockbox'
-      docs: ['Initialize a new LockBox vault with a target amount']
-      discriminator: [246, 70, 73, 242, 138, 236, 46, 130]
+      name: 'closeContribution'
+      docs: [
+        'Close a contribution receipt and return its rent to the contributor (not while a',
+        'crowdfund may still need it for refunds)',
+      ]
+      discriminator: [212, 162, 137, 29, 10, 95, 186, 129]
+      accounts: [
+        {
+          name: 'lockbox'
+          docs: ['deserialized in the handler while it still exists.']
+        },
+        {
+          name: 'contributor'
+          writable: true
+          signer: true
+          relations: ['contribution']
+        },
+        {
+          name: 'contribution'
+          writable: true
+          pda: {
+            seeds: [
+              {
+                kind: 'const'
+                value: [99, 111, 110, 116, 114, 105, 98, 117, 116, 105, 111, 110]
+              },
+              {
+                kind: 'account'
+                path: 'lockbox'
+              },
+              {
+                kind: 'account'
+                path: 'contributor'
+              },
+            ]
+          }
+        },
+        {
+          name: 'config'
+          pda: {
+            seeds: [
+              {
+                kind: 'const'
+                value: [99, 111, 110, 102, 105, 103]
+              },
+            ]
+          }
+        },
+      ]
+      args: []
+    },
+    {
+      name: 'closeLockbox'
+      docs: ['Close the LockBox account and reclaim rent (vault must be empty, LockBox not paused)']
+      discriminator: [249, 5, 46, 49, 123, 1, 211, 26]
       accounts: [
         {
           name: 'lockbox'
@@ -184,7 +381,13 @@ export type LockBoxAnchor = {
               },
               {
                 kind: 'account'
-                path: 'owner'
+                path: 'lockbox.creator'
+                account: 'lockBox'
+              },
+              {
+                kind: 'account'
+                path: 'lockbox.id'
+                account: 'lockBox'
               },
             ]
           }
@@ -193,23 +396,60 @@ export type LockBoxAnchor = {
           name: 'owner'
           writable: true
           signer: true
+          relations: ['lockbox']
+        },
+        {
+          name: 'vault'
+          writable: true
+          pda: {
+            seeds: [
+              {
+                kind: 'const'
+                value: [118, 97, 117, 108, 116]
+              },
+              {
+                kind: 'account'
+                path: 'lockbox'
+              },
+            ]
+          }
+        },
+        {
+          name: 'config'
+          pda: {
+            seeds: [
+              {
+                kind: 'const'
+                value: [99, 111, 110, 102, 105, 103]
+              },
+            ]
+          }
         },
         {
           name: 'systemProgram'
           address: '11111111111111111111111111111111'
         },
-      ]
-      args: [
         {
-          name: 'targetAmount'
-          type: 'u64'
+          name: 'eventAuthority'
+          pda: {
+            seeds: [
+              {
+                kind: 'const'
+                value: [95, 95, 101, 118, 101, 110, 116, 95, 97, 117, 116, 104, 111, 114, 105, 116, 121]
+              },
+            ]
+          }
+        },
+        {
+          name: 'program'
         },
       ]
+      args: []
     },
     {
-      name: 'withdraw'
-      docs: ['Withdraw SOL from the LockBox vault (only when target is reached)']
-      discriminator: [183, 18, 70, 156, 148, 109, 161, 34]
+      name: 'closeTokenLockbox'
+      docs: ['Close a token LockBox and its empty token vault, returning rent to the owner']
+      discriminator: [51, 62, 183, 218, 140, 230, 157, 53]
       accounts: [
         {
           name: 'lockbox'
@@ -222,7 +462,13 @@ export type LockBoxAnchor = {
               },
               {
                 kind: 'account'
-                path: 'owner'
+                path: 'lockbox.creator'
+                account: 'lockBox'
+              },
+              {
+                kind: 'account'
+                path: 'lockbox.id'
+                account: 'lockBox'
               },
             ]
           }
@@ -233,6 +479,10 @@ export type LockBoxAnchor = {
           signer: true
           relations: ['lockbox']
         },
+        {
+          name: 'mint'
+          writable: true
+        },
         {
           name: 'vault'
           writable: true
@@ -250,87 +500,3306 @@ export type LockBoxAnchor = {
           }
         },
         {
-          name: 'systemProgram'
-          address: '11111111111111111111111111111111'
-        },
-      ]
-      args: [
-        {
-          name: 'amount'
-          type: 'u64'
+          name: 'config'
+          pda: {
+            seeds: [
+              {
+                kind: 'const'
+                value: [99, 111, 110, 102, 105, 103]
+              },
+            ]
+          }
+        },
+        {
+          name: 'tokenProgram'
+        },
+        {
+          name: 'eventAuthority'
+          pda: {
+            seeds: [
+              {
+                kind: 'const'
+                value: [95, 95, 101, 118, 101, 110, 116, 95, 97, 117, 116, 104, 111, 114, 105, 116, 121]
+              },
+            ]
+          }
+        },
+        {
+          name: 'program'
+        },
+      ]
+      args: []
+    },
+    {
+      name: 'contribute'
+      docs: ["Contribute SOL to someone else's LockBox (any signer; only the owner can withdraw)"]
+      discriminator: [82, 33, 68, 131, 32, 0, 205, 95]
+      accounts: [
+        {
+          name: 'lockbox'
+          writable: true
+          pda: {
+            seeds: [
+              {
+                kind: 'const'
+                value: [108, 111, 99, 107, 98, 111, 120]
+              },
+              {
+                kind: 'account'
+                path: 'lockbox.creator'
+                account: 'lockBox'
+              },
+              {
+                kind: 'account'
+                path: 'lockbox.id'
+                account: 'lockBox'
+              },
+            ]
+          }
+        },
+        {
+          name: 'contributor'
+          writable: true
+          signer: true
+        },
+        {
+          name: 'contribution'
+          writable: true
+          pda: {
+            seeds: [
+              {
+                kind: 'const'
+                value: [99, 111, 110, 116, 114, 105, 98, 117, 116, 105, 111, 110]
+              },
+              {
+                kind: 'account'
+                path: 'lockbox'
+              },
+              {
+                kind: 'account'
+                path: 'contributor'
+              },
+            ]
+          }
+        },
+        {
+          name: 'vault'
+          writable: true
+          pda: {
+            seeds: [
+              {
+                kind: 'const'
+                value: [118, 97, 117, 108, 116]
+              },
+              {
+                kind: 'account'
+                path: 'lockbox'
+              },
+            ]
+          }
+        },
+        {
+          name: 'config'
+          pda: {
+            seeds: [
+              {
+                kind: 'const'
+                value: [99, 111, 110, 102, 105, 103]
+              },
+            ]
+          }
+        },
+        {
+          name: 'systemProgram'
+          address: '11111111111111111111111111111111'
+        },
+        {
+          name: 'eventAuthority'
+          pda: {
+            seeds: [
+              {
+                kind: 'const'
+                value: [95, 95, 101, 118, 101, 110, 116, 95, 97, 117, 116, 104, 111, 114, 105, 116, 121]
+              },
+            ]
+          }
+        },
+        {
+          name: 'program'
+        },
+      ]
+      args: [
+        {
+          name: 'amount'
+          type: 'u64'
+        },
+      ]
+    },
+    {
+      name: 'createProposal'
+      docs: [
+        'Propose a withdrawal, closing, pause or resume of a multisig LockBox (counts as the',
+        "proposer's approval)",
+      ]
+      discriminator: [132, 116, 68, 174, 216, 160, 198, 22]
+      accounts: [
+        {
+          name: 'lockbox'
+          writable: true
+          pda: {
+            seeds: [
+              {
+                kind: 'const'
+                value: [108, 111, 99, 107, 98, 111, 120]
+              },
+              {
+                kind: 'account'
+                path: 'lockbox.creator'
+                account: 'lockBox'
+              },
+              {
+                kind: 'account'
+                path: 'lockbox.id'
+                account: 'lockBox'
+              },
+            ]
+          }
+        },
+        {
+          name: 'proposal'
+          writable: true
+          pda: {
+            seeds: [
+              {
+                kind: 'const'
+                value: [112, 114, 111, 112, 111, 115, 97, 108]
+              },
+              {
+                kind: 'account'
+                path: 'lockbox'
+              },
+              {
+                kind: 'account'
+                path: 'lockbox.proposal_count'
+                account: 'lockBox'
+              },
+            ]
+          }
+        },
+        {
+          name: 'proposer'
+          writable: true
+          signer: true
+        },
+        {
+          name: 'config'
+          pda: {
+            seeds: [
+              {
+                kind: 'const'
+                value: [99, 111, 110, 102, 105, 103]
+              },
+            ]
+          }
+        },
+        {
+          name: 'systemProgram'
+          address: '11111111111111111111111111111111'
+        },
+        {
+          name: 'eventAuthority'
+          pda: {
+            seeds: [
+              {
+                kind: 'const'
+                value: [95, 95, 101, 118, 101, 110, 116, 95, 97, 117, 116, 104, 111, 114, 105, 116, 121]
+              },
+            ]
+          }
+        },
+        {
+          name: 'program'
+        },
+      ]
+      args: [
+        {
+          name: 'action'
+          type: {
+            defined: {
+              name: 'proposalAction'
+            }
+          }
+        },
+      ]
+    },
+    {
+      name: 'deposit'
+      docs: ['Deposit SOL into the LockBox vault']
+      discriminator: [242, 35, 198, 137, 82, 225, 242, 182]
+      accounts: [
+        {
+          name: 'lockbox'
+          writable: true
+          pda: {
+            seeds: [
+              {
+                kind: 'const'
+                value: [108, 111, 99, 107, 98, 111, 120]
+              },
+              {
+                kind: 'account'
+                path: 'lockbox.creator'
+                account: 'lockBox'
+              },
+              {
+                kind: 'account'
+                path: 'lockbox.id'
+                account: 'lockBox'
+              },
+            ]
+          }
+        },
+        {
+          name: 'owner'
+          writable: true
+          signer: true
+          relations: ['lockbox']
+        },
+        {
+          name: 'vault'
+          writable: true
+          pda: {
+            seeds: [
+              {
+                kind: 'const'
+                value: [118, 97, 117, 108, 116]
+              },
+              {
+                kind: 'account'
+                path: 'lockbox'
+              },
+            ]
+          }
+        },
+        {
+          name: 'config'
+          pda: {
+            seeds: [
+              {
+                kind: 'const'
+                value: [99, 111, 110, 102, 105, 103]
+              },
+            ]
+          }
+        },
+        {
+          name: 'systemProgram'
+          address: '11111111111111111111111111111111'
+        },
+        {
+          name: 'eventAuthority'
+          pda: {
+            seeds: [
+              {
+                kind: 'const'
+                value: [95, 95, 101, 118, 101, 110, 116, 95, 97, 117, 116, 104, 111, 114, 105, 116, 121]
+              },
+            ]
+          }
+        },
+        {
+          name: 'program'
         },
       ]
+      args: [
+        {
+          name: 'amount'
+          type: 'u64'
+        },
+      ]
+    },
+    {
+      name: 'depositToken'
+      docs: ['Deposit tokens into a token LockBox vault']
+      discriminator: [11, 156, 96, 218, 39, 163, 180, 19]
+      accounts: [
+        {
+          name: 'lockbox'
+          writable: true
+          pda: {
+            seeds: [
+              {
+                kind: 'const'
+                value: [108, 111, 99, 107, 98, 111, 120]
+              },
+              {
+                kind: 'account'
+                path: 'lockbox.creator'
+                account: 'lockBox'
+              },
+              {
+                kind: 'account'
+                path: 'lockbox.id'
+                account: 'lockBox'
+              },
+            ]
+          }
+        },
+        {
+          name: 'owner'
+          signer: true
+          relations: ['lockbox']
+        },
+        {
+          name: 'mint'
+        },
+        {
+          name: 'ownerTokenAccount'
+          writable: true
+        },
+        {
+          name: 'vault'
+          writable: true
+          pda: {
+            seeds: [
+              {
+                kind: 'const'
+                value: [118, 97, 117, 108, 116]
+              },
+              {
+                kind: 'account'
+                path: 'lockbox'
+              },
+            ]
+          }
+        },
+        {
+          name: 'config'
+          pda: {
+            seeds: [
+              {
+                kind: 'const'
+                value: [99, 111, 110, 102, 105, 103]
+              },
+            ]
+          }
+        },
+        {
+          name: 'tokenProgram'
+        },
+        {
+          name: 'eventAuthority'
+          pda: {
+            seeds: [
+              {
+                kind: 'const'
+                value: [95, 95, 101, 118, 101, 110, 116, 95, 97, 117, 116, 104, 111, 114, 105, 116, 121]
+              },
+            ]
+          }
+        },
+        {
+          name: 'program'
+        },
+      ]
+      args: [
+        {
+          name: 'amount'
+          type: 'u64'
+        },
+      ]
+    },
+    {
+      name: 'emergencyWithdraw'
+      docs: [
+        'Emergency withdrawal - withdraws all funds minus the penalty (sent to the treasury).',
+        'With `keep_record` the LockBox stays as a permanently broken record, otherwise it is',
+        'closed and its rent returned. Guardians must co-sign if registered.',
+      ]
+      discriminator: [239, 45, 203, 64, 150, 73, 218, 92]
+      accounts: [
+        {
+          name: 'lockbox'
+          writable: true
+          pda: {
+            seeds: [
+              {
+                kind: 'const'
+                value: [108, 111, 99, 107, 98, 111, 120]
+              },
+              {
+                kind: 'account'
+                path: 'lockbox.creator'
+                account: 'lockBox'
+              },
+              {
+                kind: 'account'
+                path: 'lockbox.id'
+                account: 'lockBox'
+              },
+            ]
+          }
+        },
+        {
+          name: 'owner'
+          writable: true
+          signer: true
+          relations: ['lockbox']
+        },
+        {
+          name: 'guardian1'
+          signer: true
+          optional: true
+        },
+        {
+          name: 'guardian2'
+          signer: true
+          optional: true
+        },
+        {
+          name: 'guardian3'
+          signer: true
+          optional: true
+        },
+        {
+          name: 'beneficiary'
+          writable: true
+          optional: true
+        },
+        {
+          name: 'vault'
+          writable: true
+          pda: {
+            seeds: [
+              {
+                kind: 'const'
+                value: [118, 97, 117, 108, 116]
+              },
+              {
+                kind: 'account'
+                path: 'lockbox'
+              },
+            ]
+          }
+        },
+        {
+          name: 'config'
+          pda: {
+            seeds: [
+              {
+                kind: 'const'
+                value: [99, 111, 110, 102, 105, 103]
+              },
+            ]
+          }
+        },
+        {
+          name: 'treasury'
+          writable: true
+        },
+        {
+          name: 'systemProgram'
+          address: '11111111111111111111111111111111'
+        },
+        {
+          name: 'eventAuthority'
+          pda: {
+            seeds: [
+              {
+                kind: 'const'
+                value: [95, 95, 101, 118, 101, 110, 116, 95, 97, 117, 116, 104, 111, 114, 105, 116, 121]
+              },
+            ]
+          }
+        },
+        {
+          name: 'program'
+        },
+      ]
+      args: [
+        {
+          name: 'keepRecord'
+          type: 'bool'
+        },
+      ]
+    },
+    {
+      name: 'emergencyWithdrawToken'
+      docs: ["Emergency withdrawal for token LockBoxes - the penalty goes to the treasury's token account"]
+      discriminator: [223, 158, 99, 222, 87, 171, 16, 162]
+      accounts: [
+        {
+          name: 'lockbox'
+          writable: true
+          pda: {
+            seeds: [
+              {
+                kind: 'const'
+                value: [108, 111, 99, 107, 98, 111, 120]
+              },
+              {
+                kind: 'account'
+                path: 'lockbox.creator'
+                account: 'lockBox'
+              },
+              {
+                kind: 'account'
+                path: 'lockbox.id'
+                account: 'lockBox'
+              },
+            ]
+          }
+        },
+        {
+          name: 'owner'
+          writable: true
+          signer: true
+          relations: ['lockbox']
+        },
+        {
+          name: 'guardian1'
+          signer: true
+          optional: true
+        },
+        {
+          name: 'guardian2'
+          signer: true
+          optional: true
+        },
+        {
+          name: 'guardian3'
+          signer: true
+          optional: true
+        },
+        {
+          name: 'mint'
+          writable: true
+        },
+        {
+          name: 'ownerTokenAccount'
+          writable: true
+        },
+        {
+          name: 'beneficiaryTokenAccount'
+          writable: true
+          optional: true
+        },
+        {
+          name: 'vault'
+          writable: true
+          pda: {
+            seeds: [
+              {
+                kind: 'const'
+                value: [118, 97, 117, 108, 116]
+              },
+              {
+                kind: 'account'
+                path: 'lockbox'
+              },
+            ]
+          }
+        },
+        {
+          name: 'config'
+          pda: {
+            seeds: [
+              {
+                kind: 'const'
+                value: [99, 111, 110, 102, 105, 103]
+              },
+            ]
+          }
+        },
+        {
+          name: 'treasuryTokenAccount'
+          writable: true
+        },
+        {
+          name: 'tokenProgram'
+        },
+        {
+          name: 'eventAuthority'
+          pda: {
+            seeds: [
+              {
+                kind: 'const'
+                value: [95, 95, 101, 118, 101, 110, 116, 95, 97, 117, 116, 104, 111, 114, 105, 116, 121]
+              },
+            ]
+          }
+        },
+        {
+          name: 'program'
+        },
+      ]
+      args: [
+        {
+          name: 'keepRecord'
+          type: 'bool'
+        },
+      ]
+    },
+    {
+      name: 'executeProposal'
+      docs: ['Carry out a proposal that reached the threshold, signing with the vault PDA']
+      discriminator: [186, 60, 116, 133, 108, 128, 111, 28]
+      accounts: [
+        {
+          name: 'lockbox'
+          writable: true
+          pda: {
+            seeds: [
+              {
+                kind: 'const'
+                value: [108, 111, 99, 107, 98, 111, 120]
+              },
+              {
+                kind: 'account'
+                path: 'lockbox.creator'
+                account: 'lockBox'
+              },
+              {
+                kind: 'account'
+                path: 'lockbox.id'
+                account: 'lockBox'
+              },
+            ]
+          }
+          relations: ['proposal']
+        },
+        {
+          name: 'proposal'
+          writable: true
+          pda: {
+            seeds: [
+              {
+                kind: 'const'
+                value: [112, 114, 111, 112, 111, 115, 97, 108]
+              },
+              {
+                kind: 'account'
+                path: 'lockbox'
+              },
+              {
+                kind: 'account'
+                path: 'proposal.id'
+                account: 'proposal'
+              },
+            ]
+          }
+        },
+        {
+          name: 'executor'
+          signer: true
+        },
+        {
+          name: 'proposer'
+          writable: true
+          relations: ['proposal']
+        },
+        {
+          name: 'owner'
+          writable: true
+          relations: ['lockbox']
+        },
+        {
+          name: 'beneficiary'
+          writable: true
+          optional: true
+        },
+        {
+          name: 'vault'
+          writable: true
+          pda: {
+            seeds: [
+              {
+                kind: 'const'
+                value: [118, 97, 117, 108, 116]
+              },
+              {
+                kind: 'account'
+                path: 'lockbox'
+              },
+            ]
+          }
+        },
+        {
+          name: 'config'
+          pda: {
+            seeds: [
+              {
+                kind: 'const'
+                value: [99, 111, 110, 102, 105, 103]
+              },
+            ]
+          }
+        },
+        {
+          name: 'systemProgram'
+          address: '11111111111111111111111111111111'
+        },
+        {
+          name: 'eventAuthority'
+          pda: {
+            seeds: [
+              {
+                kind: 'const'
+                value: [95, 95, 101, 118, 101, 110, 116, 95, 97, 117, 116, 104, 111, 114, 105, 116, 121]
+              },
+            ]
+          }
+        },
+        {
+          name: 'program'
+        },
+      ]
+      args: []
+    },
+    {
+      name: 'initializeConfig'
+      docs: ['Create the global Config (upgrade authority only)']
+      discriminator: [208, 127, 21, 1, 194, 190, 196, 70]
+      accounts: [
+        {
+          name: 'config'
+          writable: true
+          pda: {
+            seeds: [
+              {
+                kind: 'const'
+                value: [99, 111, 110, 102, 105, 103]
+              },
+            ]
+          }
+        },
+        {
+          name: 'admin'
+          writable: true
+          signer: true
+        },
+        {
+          name: 'program'
+          address: 'FkFyFob5oYm4Q9aukvK1ttXduveWh16HYmhCvMXyw6tr'
+        },
+        {
+          name: 'programData'
+        },
+        {
+          name: 'systemProgram'
+          address: '11111111111111111111111111111111'
+        },
+      ]
+      args: [
+        {
+          name: 'params'
+          type: {
+            defined: {
+              name: 'configParams'
+            }
+          }
+        },
+      ]
+    },
+    {
+      name: 'initializeLockbox'
+      docs: [
+        'Initialize a new SOL LockBox vault with a target amount, unlock policy and',
+        'early-exit penalty schedule. `lockbox_id` lets one owner keep several independent LockBoxes.',
+        'A `crowdfund_deadline` turns the LockBox into an all-or-nothing crowdfund, and a',
+        '`beneficiary` receives withdrawals instead of the owner.',
+      ]
+      discriminator: [246, 70, 73, 242, 138, 236, 46, 130]
+      accounts: [
+        {
+          name: 'lockbox'
+          writable: true
+          pda: {
+            seeds: [
+              {
+                kind: 'const'
+                value: [108, 111, 99, 107, 98, 111, 120]
+              },
+              {
+                kind: 'account'
+                path: 'owner'
+              },
+              {
+                kind: 'arg'
+                path: 'lockboxId'
+              },
+            ]
+          }
+        },
+        {
+          name: 'owner'
+          writable: true
+          signer: true
+        },
+        {
+          name: 'config'
+          writable: true
+          pda: {
+            seeds: [
+              {
+                kind: 'const'
+                value: [99, 111, 110, 102, 105, 103]
+              },
+            ]
+          }
+        },
+        {
+          name: 'systemProgram'
+          address: '11111111111111111111111111111111'
+        },
+        {
+          name: 'eventAuthority'
+          pda: {
+            seeds: [
+              {
+                kind: 'const'
+                value: [95, 95, 101, 118, 101, 110, 116, 95, 97, 117, 116, 104, 111, 114, 105, 116, 121]
+              },
+            ]
+          }
+        },
+        {
+          name: 'program'
+        },
+      ]
+      args: [
+        {
+          name: 'lockboxId'
+          type: 'u64'
+        },
+        {
+          name: 'params'
+          type: {
+            defined: {
+              name: 'lockBoxParams'
+            }
+          }
+        },
+      ]
+    },
+    {
+      name: 'initializeTokenLockbox'
+      docs: ['Initialize a LockBox that saves an SPL token; its vault is a token account owned by the vault PDA']
+      discriminator: [145, 233, 222, 116, 189, 255, 226, 191]
+      accounts: [
+        {
+          name: 'lockbox'
+          writable: true
+          pda: {
+            seeds: [
+              {
+                kind: 'const'
+                value: [108, 111, 99, 107, 98, 111, 120]
+              },
+              {
+                kind: 'account'
+                path: 'owner'
+              },
+              {
+                kind: 'arg'
+                path: 'lockboxId'
+              },
+            ]
+          }
+        },
+        {
+          name: 'owner'
+          writable: true
+          signer: true
+        },
+        {
+          name: 'mint'
+        },
+        {
+          name: 'vault'
+          writable: true
+          pda: {
+            seeds: [
+              {
+                kind: 'const'
+                value: [118, 97, 117, 108, 116]
+              },
+              {
+                kind: 'account'
+                path: 'lockbox'
+              },
+            ]
+          }
+        },
+        {
+          name: 'config'
+          writable: true
+          pda: {
+            seeds: [
+              {
+                kind: 'const'
+                value: [99, 111, 110, 102, 105, 103]
+              },
+            ]
+          }
+        },
+        {
+          name: 'tokenProgram'
+        },
+        {
+          name: 'systemProgram'
+          address: '11111111111111111111111111111111'
+        },
+        {
+          name: 'eventAuthority'
+          pda: {
+            seeds: [
+              {
+                kind: 'const'
+                value: [95, 95, 101, 118, 101, 110, 116, 95, 97, 117, 116, 104, 111, 114, 105, 116, 121]
+              },
+            ]
+          }
+        },
+        {
+          name: 'program'
+        },
+      ]
+      args: [
+        {
+          name: 'lockboxId'
+          type: 'u64'
+        },
+        {
+          name: 'params'
+          type: {
+            defined: {
+              name: 'lockBoxParams'
+            }
+          }
+        },
+      ]
+    },
+    {
+      name: 'pauseLockbox'
+      docs: [
+        'Freeze the LockBox (no deposits, withdrawals or emergency exits), e.g. while travelling',
+        'or after a suspected key compromise. `paused_until` ends the pause automatically.',
+      ]
+      discriminator: [105, 47, 26, 143, 107, 55, 209, 253]
+      accounts: [
+        {
+          name: 'lockbox'
+          writable: true
+          pda: {
+            seeds: [
+              {
+                kind: 'const'
+                value: [108, 111, 99, 107, 98, 111, 120]
+              },
+              {
+                kind: 'account'
+                path: 'lockbox.creator'
+                account: 'lockBox'
+              },
+              {
+                kind: 'account'
+                path: 'lockbox.id'
+                account: 'lockBox'
+              },
+            ]
+          }
+        },
+        {
+          name: 'owner'
+          signer: true
+          relations: ['lockbox']
+        },
+        {
+          name: 'config'
+          pda: {
+            seeds: [
+              {
+                kind: 'const'
+                value: [99, 111, 110, 102, 105, 103]
+              },
+            ]
+          }
+        },
+        {
+          name: 'eventAuthority'
+          pda: {
+            seeds: [
+              {
+                kind: 'const'
+                value: [95, 95, 101, 118, 101, 110, 116, 95, 97, 117, 116, 104, 111, 114, 105, 116, 121]
+              },
+            ]
+          }
+        },
+        {
+          name: 'program'
+        },
+      ]
+      args: [
+        {
+          name: 'pausedUntil'
+          type: {
+            option: 'i64'
+          }
+        },
+      ]
+    },
+    {
+      name: 'proposeOwner'
+      docs: ['Propose a new owner for the LockBox (or cancel with `None`); takes effect on acceptance']
+      discriminator: [90, 57, 141, 110, 196, 241, 172, 39]
+      accounts: [
+        {
+          name: 'lockbox'
+          writable: true
+          pda: {
+            seeds: [
+              {
+                kind: 'const'
+                value: [108, 111, 99, 107, 98, 111, 120]
+              },
+              {
+                kind: 'account'
+                path: 'lockbox.creator'
+                account: 'lockBox'
+              },
+              {
+                kind: 'account'
+                path: 'lockbox.id'
+                account: 'lockBox'
+              },
+            ]
+          }
+        },
+        {
+          name: 'owner'
+          signer: true
+          relations: ['lockbox']
+        },
+        {
+          name: 'config'
+          pda: {
+            seeds: [
+              {
+                kind: 'const'
+                value: [99, 111, 110, 102, 105, 103]
+              },
+            ]
+          }
+        },
+        {
+          name: 'eventAuthority'
+          pda: {
+            seeds: [
+              {
+                kind: 'const'
+                value: [95, 95, 101, 118, 101, 110, 116, 95, 97, 117, 116, 104, 111, 114, 105, 116, 121]
+              },
+            ]
+          }
+        },
+        {
+          name: 'program'
+        },
+      ]
+      args: [
+        {
+          name: 'newOwner'
+          type: {
+            option: 'pubkey'
+          }
+        },
+      ]
+    },
+    {
+      name: 'resumeLockbox'
+      docs: [
+        'Resume a paused LockBox: by the guardians if it has any, otherwise by the owner.',
+        'Multisig LockBoxes without guardians resume through a proposal.',
+      ]
+      discriminator: [126, 71, 226, 189, 31, 70, 216, 220]
+      accounts: [
+        {
+          name: 'lockbox'
+          writable: true
+          pda: {
+            seeds: [
+              {
+                kind: 'const'
+                value: [108, 111, 99, 107, 98, 111, 120]
+              },
+              {
+                kind: 'account'
+                path: 'lockbox.creator'
+                account: 'lockBox'
+              },
+              {
+                kind: 'account'
+                path: 'lockbox.id'
+                account: 'lockBox'
+              },
+            ]
+          }
+        },
+        {
+          name: 'owner'
+          signer: true
+          optional: true
+        },
+        {
+          name: 'guardian1'
+          signer: true
+          optional: true
+        },
+        {
+          name: 'guardian2'
+          signer: true
+          optional: true
+        },
+        {
+          name: 'guardian3'
+          signer: true
+          optional: true
+        },
+        {
+          name: 'config'
+          pda: {
+            seeds: [
+              {
+                kind: 'const'
+                value: [99, 111, 110, 102, 105, 103]
+              },
+            ]
+          }
+        },
+        {
+          name: 'eventAuthority'
+          pda: {
+            seeds: [
+              {
+                kind: 'const'
+                value: [95, 95, 101, 118, 101, 110, 116, 95, 97, 117, 116, 104, 111, 114, 105, 116, 121]
+              },
+            ]
+          }
+        },
+        {
+          name: 'program'
+        },
+      ]
+      args: []
+    },
+    {
+      name: 'setBeneficiary'
+      docs: ['Set or clear the beneficiary that receives withdrawals; an existing beneficiary must co-sign']
+      discriminator: [10, 81, 219, 4, 237, 149, 57, 242]
+      accounts: [
+        {
+          name: 'lockbox'
+          writable: true
+          pda: {
+            seeds: [
+              {
+                kind: 'const'
+                value: [108, 111, 99, 107, 98, 111, 120]
+              },
+              {
+                kind: 'account'
+                path: 'lockbox.creator'
+                account: 'lockBox'
+              },
+              {
+                kind: 'account'
+                path: 'lockbox.id'
+                account: 'lockBox'
+              },
+            ]
+          }
+        },
+        {
+          name: 'owner'
+          signer: true
+          relations: ['lockbox']
+        },
+        {
+          name: 'currentBeneficiary'
+          signer: true
+          optional: true
+        },
+        {
+          name: 'config'
+          pda: {
+            seeds: [
+              {
+                kind: 'const'
+                value: [99, 111, 110, 102, 105, 103]
+              },
+            ]
+          }
+        },
+        {
+          name: 'eventAuthority'
+          pda: {
+            seeds: [
+              {
+                kind: 'const'
+                value: [95, 95, 101, 118, 101, 110, 116, 95, 97, 117, 116, 104, 111, 114, 105, 116, 121]
+              },
+            ]
+          }
+        },
+        {
+          name: 'program'
+        },
+      ]
+      args: [
+        {
+          name: 'beneficiary'
+          type: {
+            option: 'pubkey'
+          }
+        },
+      ]
+    },
+    {
+      name: 'setGuardians'
+      docs: [
+        'Register up to three guardians and how many of them must co-sign an emergency',
+        "withdrawal. Changing an existing set needs the current guardians' approval.",
+      ]
+      discriminator: [166, 69, 140, 183, 157, 169, 253, 40]
+      accounts: [
+        {
+          name: 'lockbox'
+          writable: true
+          pda: {
+            seeds: [
+              {
+                kind: 'const'
+                value: [108, 111, 99, 107, 98, 111, 120]
+              },
+              {
+                kind: 'account'
+                path: 'lockbox.creator'
+                account: 'lockBox'
+              },
+              {
+                kind: 'account'
+                path: 'lockbox.id'
+                account: 'lockBox'
+              },
+            ]
+          }
+        },
+        {
+          name: 'owner'
+          signer: true
+          relations: ['lockbox']
+        },
+        {
+          name: 'guardian1'
+          signer: true
+          optional: true
+        },
+        {
+          name: 'guardian2'
+          signer: true
+          optional: true
+        },
+        {
+          name: 'guardian3'
+          signer: true
+          optional: true
+        },
+        {
+          name: 'config'
+          pda: {
+            seeds: [
+              {
+                kind: 'const'
+                value: [99, 111, 110, 102, 105, 103]
+              },
+            ]
+          }
+        },
+        {
+          name: 'eventAuthority'
+          pda: {
+            seeds: [
+              {
+                kind: 'const'
+                value: [95, 95, 101, 118, 101, 110, 116, 95, 97, 117, 116, 104, 111, 114, 105, 116, 121]
+              },
+            ]
+          }
+        },
+        {
+          name: 'program'
+        },
+      ]
+      args: [
+        {
+          name: 'guardians'
+          type: {
+            vec: 'pubkey'
+          }
+        },
+        {
+          name: 'threshold'
+          type: 'u8'
+        },
+      ]
+    },
+    {
+      name: 'setHeir'
+      docs: [
+        'Name an heir who can take over the LockBox once the owner has been inactive for',
+        '`inactivity_period` seconds (`None` removes the heir). Requires guardian approval and the',
+        "current beneficiary's signature when the LockBox has them.",
+      ]
+      discriminator: [137, 84, 217, 74, 123, 26, 198, 145]
+      accounts: [
+        {
+          name: 'lockbox'
+          writable: true
+          pda: {
+            seeds: [
+              {
+                kind: 'const'
+                value: [108, 111, 99, 107, 98, 111, 120]
+              },
+              {
+                kind: 'account'
+                path: 'lockbox.creator'
+                account: 'lockBox'
+              },
+              {
+                kind: 'account'
+                path: 'lockbox.id'
+                account: 'lockBox'
+              },
+            ]
+          }
+        },
+        {
+          name: 'owner'
+          signer: true
+          relations: ['lockbox']
+        },
+        {
+          name: 'guardian1'
+          signer: true
+          optional: true
+        },
+        {
+          name: 'guardian2'
+          signer: true
+          optional: true
+        },
+        {
+          name: 'guardian3'
+          signer: true
+          optional: true
+        },
+        {
+          name: 'currentBeneficiary'
+          signer: true
+          optional: true
+        },
+        {
+          name: 'config'
+          pda: {
+            seeds: [
+              {
+                kind: 'const'
+                value: [99, 111, 110, 102, 105, 103]
+              },
+            ]
+          }
+        },
+        {
+          name: 'eventAuthority'
+          pda: {
+            seeds: [
+              {
+                kind: 'const'
+                value: [95, 95, 101, 118, 101, 110, 116, 95, 97, 117, 116, 104, 111, 114, 105, 116, 121]
+              },
+            ]
+          }
+        },
+        {
+          name: 'program'
+        },
+      ]
+      args: [
+        {
+          name: 'heir'
+          type: {
+            option: 'pubkey'
+          }
+        },
+        {
+          name: 'inactivityPeriod'
+          type: 'i64'
+        },
+      ]
+    },
+    {
+      name: 'setMultisig'
+      docs: [
+        'Hand a SOL LockBox over to an m-of-n signer set; from then on withdrawals',
+        'and closing only happen through approved proposals.',
+      ]
+      discriminator: [251, 6, 245, 35, 115, 42, 77, 186]
+      accounts: [
+        {
+          name: 'lockbox'
+          writable: true
+          pda: {
+            seeds: [
+              {
+                kind: 'const'
+                value: [108, 111, 99, 107, 98, 111, 120]
+              },
+              {
+                kind: 'account'
+                path: 'lockbox.creator'
+                account: 'lockBox'
+              },
+              {
+                kind: 'account'
+                path: 'lockbox.id'
+                account: 'lockBox'
+              },
+            ]
+          }
+        },
+        {
+          name: 'owner'
+          signer: true
+          relations: ['lockbox']
+        },
+        {
+          name: 'config'
+          pda: {
+            seeds: [
+              {
+                kind: 'const'
+                value: [99, 111, 110, 102, 105, 103]
+              },
+            ]
+          }
+        },
+        {
+          name: 'eventAuthority'
+          pda: {
+            seeds: [
+              {
+                kind: 'const'
+                value: [95, 95, 101, 118, 101, 110, 116, 95, 97, 117, 116, 104, 111, 114, 105, 116, 121]
+              },
+            ]
+          }
+        },
+        {
+          name: 'program'
+        },
+      ]
+      args: [
+        {
+          name: 'signers'
+          type: {
+            vec: 'pubkey'
+          }
+        },
+        {
+          name: 'threshold'
+          type: 'u8'
+        },
+      ]
+    },
+    {
+      name: 'setWithdrawalLimit'
+      docs: [
+        'Cap withdrawals at `limit` per rolling `window` of seconds (e.g. a weekly budget); 0/0 removes the cap.',
+        'Once the LockBox is unlocked the cap can only be tightened.',
+      ]
+      discriminator: [97, 243, 160, 126, 155, 129, 70, 184]
+      accounts: [
+        {
+          name: 'lockbox'
+          writable: true
+          pda: {
+            seeds: [
+              {
+                kind: 'const'
+                value: [108, 111, 99, 107, 98, 111, 120]
+              },
+              {
+                kind: 'account'
+                path: 'lockbox.creator'
+                account: 'lockBox'
+              },
+              {
+                kind: 'account'
+                path: 'lockbox.id'
+                account: 'lockBox'
+              },
+            ]
+          }
+        },
+        {
+          name: 'owner'
+          signer: true
+          relations: ['lockbox']
+        },
+        {
+          name: 'config'
+          pda: {
+            seeds: [
+              {
+                kind: 'const'
+                value: [99, 111, 110, 102, 105, 103]
+              },
+            ]
+          }
+        },
+        {
+          name: 'eventAuthority'
+          pda: {
+            seeds: [
+              {
+                kind: 'const'
+                value: [95, 95, 101, 118, 101, 110, 116, 95, 97, 117, 116, 104, 111, 114, 105, 116, 121]
+              },
+            ]
+          }
+        },
+        {
+          name: 'program'
+        },
+      ]
+      args: [
+        {
+          name: 'limit'
+          type: 'u64'
+        },
+        {
+          name: 'window'
+          type: 'i64'
+        },
+      ]
+    },
+    {
+      name: 'syncBalance'
+      docs: [
+        'Fold lamports sent straight to the vault into the tracked balance (anyone can call).',
+        'A failed crowdfund pays them to the owner instead.',
+      ]
+      discriminator: [118, 211, 132, 160, 168, 121, 27, 134]
+      accounts: [
+        {
+          name: 'lockbox'
+          writable: true
+          pda: {
+            seeds: [
+              {
+                kind: 'const'
+                value: [108, 111, 99, 107, 98, 111, 120]
+              },
+              {
+                kind: 'account'
+                path: 'lockbox.creator'
+                account: 'lockBox'
+              },
+              {
+                kind: 'account'
+                path: 'lockbox.id'
+                account: 'lockBox'
+              },
+            ]
+          }
+        },
+        {
+          name: 'owner'
+          writable: true
+          relations: ['lockbox']
+        },
+        {
+          name: 'vault'
+          writable: true
+          pda: {
+            seeds: [
+              {
+                kind: 'const'
+                value: [118, 97, 117, 108, 116]
+              },
+              {
+                kind: 'account'
+                path: 'lockbox'
+              },
+            ]
+          }
+        },
+        {
+          name: 'config'
+          pda: {
+            seeds: [
+              {
+                kind: 'const'
+                value: [99, 111, 110, 102, 105, 103]
+              },
+            ]
+          }
+        },
+        {
+          name: 'systemProgram'
+          address: '11111111111111111111111111111111'
+        },
+        {
+          name: 'eventAuthority'
+          pda: {
+            seeds: [
+              {
+                kind: 'const'
+                value: [95, 95, 101, 118, 101, 110, 116, 95, 97, 117, 116, 104, 111, 114, 105, 116, 121]
+              },
+            ]
+          }
+        },
+        {
+          name: 'program'
+        },
+      ]
+      args: []
+    },
+    {
+      name: 'transferAdmin'
+      docs: ['Hand the Config admin role to a new authority (both must sign)']
+      discriminator: [42, 242, 66, 106, 228, 10, 111, 156]
+      accounts: [
+        {
+          name: 'config'
+          writable: true
+          pda: {
+            seeds: [
+              {
+                kind: 'const'
+                value: [99, 111, 110, 102, 105, 103]
+              },
+            ]
+          }
+        },
+        {
+          name: 'admin'
+          signer: true
+          relations: ['config']
+        },
+        {
+          name: 'newAdmin'
+          signer: true
+        },
+      ]
+      args: []
+    },
+    {
+      name: 'updateConfig'
+      docs: ['Update the global Config parameters (admin only)']
+      discriminator: [29, 158, 252, 191, 10, 83, 219, 99]
+      accounts: [
+        {
+          name: 'config'
+          writable: true
+          pda: {
+            seeds: [
+              {
+                kind: 'const'
+                value: [99, 111, 110, 102, 105, 103]
+              },
+            ]
+          }
+        },
+        {
+          name: 'admin'
+          signer: true
+          relations: ['config']
+        },
+      ]
+      args: [
+        {
+          name: 'params'
+          type: {
+            defined: {
+              name: 'configParams'
+            }
+          }
+        },
+      ]
+    },
+    {
+      name: 'updateTarget'
+      docs: [
+        "Change the target of an Active LockBox. Raising is free; lowering needs the guardians'",
+        'approval, and either the cooldown since the last change to have passed without unlocking',
+        'the LockBox, or `pay_penalty` to pay the current early-exit penalty',
+      ]
+      discriminator: [8, 94, 134, 253, 153, 106, 123, 154]
+      accounts: [
+        {
+          name: 'lockbox'
+          writable: true
+          pda: {
+            seeds: [
+              {
+                kind: 'const'
+                value: [108, 111, 99, 107, 98, 111, 120]
+              },
+              {
+                kind: 'account'
+                path: 'lockbox.creator'
+                account: 'lockBox'
+              },
+              {
+                kind: 'account'
+                path: 'lockbox.id'
+                account: 'lockBox'
+              },
+            ]
+          }
+        },
+        {
+          name: 'owner'
+          writable: true
+          signer: true
+          relations: ['lockbox']
+        },
+        {
+          name: 'guardian1'
+          signer: true
+          optional: true
+        },
+        {
+          name: 'guardian2'
+          signer: true
+          optional: true
+        },
+        {
+          name: 'guardian3'
+          signer: true
+          optional: true
+        },
+        {
+          name: 'vault'
+          writable: true
+          pda: {
+            seeds: [
+              {
+                kind: 'const'
+                value: [118, 97, 117, 108, 116]
+              },
+              {
+                kind: 'account'
+                path: 'lockbox'
+              },
+            ]
+          }
+        },
+        {
+          name: 'config'
+          pda: {
+            seeds: [
+              {
+                kind: 'const'
+                value: [99, 111, 110, 102, 105, 103]
+              },
+            ]
+          }
+        },
+        {
+          name: 'treasury'
+          writable: true
+        },
+        {
+          name: 'systemProgram'
+          address: '11111111111111111111111111111111'
+        },
+        {
+          name: 'eventAuthority'
+          pda: {
+            seeds: [
+              {
+                kind: 'const'
+                value: [95, 95, 101, 118, 101, 110, 116, 95, 97, 117, 116, 104, 111, 114, 105, 116, 121]
+              },
+            ]
+          }
+        },
+        {
+          name: 'program'
+        },
+      ]
+      args: [
+        {
+          name: 'newTarget'
+          type: 'u64'
+        },
+        {
+          name: 'payPenalty'
+          type: 'bool'
+        },
+      ]
+    },
+    {
+      name: 'withdraw'
+      docs: ['Withdraw SOL from the LockBox vault (only once the unlock policy is satisfied)']
+      discriminator: [183, 18, 70, 156, 148, 109, 161, 34]
+      accounts: [
+        {
+          name: 'lockbox'
+          writable: true
+          pda: {
+            seeds: [
+              {
+                kind: 'const'
+                value: [108, 111, 99, 107, 98, 111, 120]
+              },
+              {
+                kind: 'account'
+                path: 'lockbox.creator'
+                account: 'lockBox'
+              },
+              {
+                kind: 'account'
+                path: 'lockbox.id'
+                account: 'lockBox'
+              },
+            ]
+          }
+        },
+        {
+          name: 'owner'
+          writable: true
+          signer: true
+          relations: ['lockbox']
+        },
+        {
+          name: 'beneficiary'
+          writable: true
+          optional: true
+        },
+        {
+          name: 'vault'
+          writable: true
+          pda: {
+            seeds: [
+              {
+                kind: 'const'
+                value: [118, 97, 117, 108, 116]
+              },
+              {
+                kind: 'account'
+                path: 'lockbox'
+              },
+            ]
+          }
+        },
+        {
+          name: 'config'
+          pda: {
+            seeds: [
+              {
+                kind: 'const'
+                value: [99, 111, 110, 102, 105, 103]
+              },
+            ]
+          }
+        },
+        {
+          name: 'systemProgram'
+          address: '11111111111111111111111111111111'
+        },
+        {
+          name: 'eventAuthority'
+          pda: {
+            seeds: [
+              {
+                kind: 'const'
+                value: [95, 95, 101, 118, 101, 110, 116, 95, 97, 117, 116, 104, 111, 114, 105, 116, 121]
+              },
+            ]
+          }
+        },
+        {
+          name: 'program'
+        },
+      ]
+      args: [
+        {
+          name: 'amount'
+          type: 'u64'
+        },
+      ]
+    },
+    {
+      name: 'withdrawToken'
+      docs: ['Withdraw tokens from a token LockBox vault (only once the unlock policy is satisfied)']
+      discriminator: [136, 235, 181, 5, 101, 109, 57, 81]
+      accounts: [
+        {
+          name: 'lockbox'
+          writable: true
+          pda: {
+            seeds: [
+              {
+                kind: 'const'
+                value: [108, 111, 99, 107, 98, 111, 120]
+              },
+              {
+                kind: 'account'
+                path: 'lockbox.creator'
+                account: 'lockBox'
+              },
+              {
+                kind: 'account'
+                path: 'lockbox.id'
+                account: 'lockBox'
+              },
+            ]
+          }
+        },
+        {
+          name: 'owner'
+          signer: true
+          relations: ['lockbox']
+        },
+        {
+          name: 'mint'
+        },
+        {
+          name: 'ownerTokenAccount'
+          writable: true
+        },
+        {
+          name: 'beneficiaryTokenAccount'
+          writable: true
+          optional: true
+        },
+        {
+          name: 'vault'
+          writable: true
+          pda: {
+            seeds: [
+              {
+                kind: 'const'
+                value: [118, 97, 117, 108, 116]
+              },
+              {
+                kind: 'account'
+                path: 'lockbox'
+              },
+            ]
+          }
+        },
+        {
+          name: 'config'
+          pda: {
+            seeds: [
+              {
+                kind: 'const'
+                value: [99, 111, 110, 102, 105, 103]
+              },
+            ]
+          }
+        },
+        {
+          name: 'tokenProgram'
+        },
+        {
+          name: 'eventAuthority'
+          pda: {
+            seeds: [
+              {
+                kind: 'const'
+                value: [95, 95, 101, 118, 101, 110, 116, 95, 97, 117, 116, 104, 111, 114, 105, 116, 121]
+              },
+            ]
+          }
+        },
+        {
+          name: 'program'
+        },
+      ]
+      args: [
+        {
+          name: 'amount'
+          type: 'u64'
+        },
+      ]
+    },
+  ]
+  accounts: [
+    {
+      name: 'config'
+      discriminator: [155, 12, 170, 224, 30, 250, 204, 130]
+    },
+    {
+      name: 'contribution'
+      discriminator: [182, 187, 14, 111, 72, 167, 242, 212]
+    },
+    {
+      name: 'lockBox'
+      discriminator: [146, 255, 195, 118, 40, 186, 40, 119]
+    },
+    {
+      name: 'proposal'
+      discriminator: [26, 94, 189, 187, 116, 136, 53, 33]
+    },
+  ]
+  events: [
+    {
+      discriminator: [111, 141, 26, 45, 161, 35, 100, 57]
+      name: 'deposited'
+    },
+    {
+      discriminator: [116, 226, 36, 3, 37, 92, 138, 76]
+      name: 'emergencyWithdrawn'
+    },
+    {
+      discriminator: [126, 245, 47, 146, 116, 28, 76, 100]
+      name: 'lockBoxClosed'
+    },
+    {
+      discriminator: [225, 104, 11, 221, 111, 129, 105, 55]
+      name: 'lockBoxCreated'
+    },
+    {
+      discriminator: [129, 236, 59, 159, 198, 200, 58, 95]
+      name: 'lockBoxPaused'
+    },
+    {
+      discriminator: [160, 198, 94, 57, 17, 237, 124, 111]
+      name: 'lockBoxResumed'
+    },
+    {
+      discriminator: [172, 61, 205, 183, 250, 50, 38, 98]
+      name: 'ownershipTransferred'
+    },
+    {
+      discriminator: [70, 49, 155, 228, 157, 43, 88, 49]
+      name: 'proposalApproved'
+    },
+    {
+      discriminator: [186, 8, 160, 108, 81, 13, 51, 206]
+      name: 'proposalCreated'
+    },
+    {
+      discriminator: [249, 207, 163, 2, 117, 111, 29, 214]
+      name: 'settingsChanged'
+    },
+    {
+      discriminator: [149, 209, 57, 9, 106, 52, 127, 219]
+      name: 'targetReached'
+    },
+    {
+      discriminator: [20, 89, 223, 198, 194, 124, 219, 13]
+      name: 'withdrawn'
+    },
+  ]
+  errors: [
+    {
+      code: 6000
+      name: 'invalidTargetAmount'
+      msg: 'Target amount must be greater than zero'
+    },
+    {
+      code: 6001
+      name: 'invalidDepositAmount'
+      msg: 'Deposit amount must be greater than zero'
+    },
+    {
+      code: 6002
+      name: 'targetNotReached'
+      msg: 'Target not reached yet. Current balance is below the target amount.'
+    },
+    {
+      code: 6003
+      name: 'vaultInactive'
+      msg: 'This vault has been deactivated and is no longer accessible'
+    },
+    {
+      code: 6004
+      name: 'unauthorized'
+      msg: 'Unauthorized: Only the owner can perform this action'
+    },
+    {
+      code: 6005
+      name: 'insufficientBalance'
+      msg: 'Insufficient balance in vault for withdrawal'
+    },
+    {
+      code: 6006
+      name: 'invalidUnlockTime'
+      msg: 'Unlock time must be in the future'
+    },
+    {
+      code: 6007
+      name: 'invalidUnlockPolicy'
+      msg: 'Unlock policy does not match the provided unlock time'
+    },
+    {
+      code: 6008
+      name: 'unlockTimeNotReached'
+      msg: 'Unlock time not reached yet. Funds are still time-locked.'
+    },
+    {
+      code: 6009
+      name: 'lockBoxPaused'
+      msg: 'This LockBox is paused'
+    },
+    {
+      code: 6010
+      name: 'invalidStatusTransition'
+      msg: "This action is not allowed in the LockBox's current status"
+    },
+    {
+      code: 6011
+      name: 'invalidPenaltyBps'
+      msg: 'Penalty exceeds the maximum allowed basis points'
+    },
+    {
+      code: 6012
+      name: 'invalidPenaltySchedule'
+      msg: 'Penalty decay duration cannot be negative'
+    },
+    {
+      code: 6013
+      name: 'invalidConfig'
+      msg: 'Invalid program configuration'
+    },
+    {
+      code: 6014
+      name: 'programPaused'
+      msg: 'The program is paused by the admin'
+    },
+    {
+      code: 6015
+      name: 'targetOutOfRange'
+      msg: 'Target amount is outside the range allowed by the program config'
+    },
+    {
+      code: 6016
+      name: 'invalidTreasury'
+      msg: 'Treasury account does not match the program config'
+    },
+    {
+      code: 6017
+      name: 'invalidCrowdfundDeadline'
+      msg: 'Crowdfund deadline must be in the future and use a target-only unlock policy'
+    },
+    {
+      code: 6018
+      name: 'crowdfundEnded'
+      msg: 'The crowdfund deadline has passed'
+    },
+    {
+      code: 6019
+      name: 'crowdfundRestricted'
+      msg: 'Crowdfund LockBoxes only accept contributions and cannot be emergency withdrawn'
+    },
+    {
+      code: 6020
+      name: 'notCrowdfund'
+      msg: 'This LockBox is not a crowdfund'
+    },
+    {
+      code: 6021
+      name: 'refundNotAvailable'
+      msg: 'Refunds are only available once a crowdfund missed its target by the deadline'
+    },
+    {
+      code: 6022
+      name: 'assetMismatch'
+      msg: "This instruction does not support the LockBox's asset (SOL or SPL token)"
+    },
+    {
+      code: 6023
+      name: 'belowRentExemptMinimum'
+      msg: 'Amount is below the rent-exempt minimum of an empty vault'
+    },
+    {
+      code: 6024
+      name: 'vaultBelowRentExemptMinimum'
+      msg: 'Withdrawal must empty the vault or leave at least its rent-exempt minimum'
+    },
+    {
+      code: 6025
+      name: 'targetCooldownActive'
+      msg: 'The target can only be lowered after the cooldown or by paying the early-exit penalty'
+    },
+    {
+      code: 6026
+      name: 'invalidPendingOwner'
+      msg: 'Signer is not the pending owner of this LockBox'
+    },
+    {
+      code: 6027
+      name: 'invalidBeneficiary'
+      msg: "Withdrawals must be paid to the LockBox's beneficiary"
+    },
+    {
+      code: 6028
+      name: 'beneficiaryConsentRequired'
+      msg: 'The current beneficiary must sign to change the beneficiary'
+    },
+    {
+      code: 6029
+      name: 'invalidGuardians'
+      msg: 'Guardians must be unique, exclude the owner, and the threshold must be between 1 and their count'
+    },
+    {
+      code: 6030
+      name: 'guardianApprovalRequired'
+      msg: 'Not enough guardians co-signed this action'
+    },
+    {
+      code: 6031
+      name: 'invalidMultisig'
+      msg: 'Multisig needs 1 to 5 unique signers and a threshold between 1 and their count'
+    },
+    {
+      code: 6032
+      name: 'multisigRequired'
+      msg: 'This LockBox is multisig-controlled; use a proposal'
+    },
+    {
+      code: 6033
+      name: 'notMultisigSigner'
+      msg: "Signer is not part of this LockBox's multisig"
+    },
+    {
+      code: 6034
+      name: 'alreadyApproved'
+      msg: 'This signer already approved the proposal'
+    },
+    {
+      code: 6035
+      name: 'thresholdNotMet'
+      msg: 'The proposal does not have enough approvals yet'
+    },
+    {
+      code: 6036
+      name: 'invalidInheritance'
+      msg: 'The heir must differ from the owner and the inactivity period must be at least the configured minimum inactivity period'
+    },
+    {
+      code: 6037
+      name: 'notHeir'
+      msg: 'Signer is not the heir of this LockBox'
+    },
+    {
+      code: 6038
+      name: 'ownerStillActive'
+      msg: 'The owner has not been inactive for the full inactivity period yet'
+    },
+    {
+      code: 6039
+      name: 'invalidVestingDuration'
+      msg: 'Vesting duration cannot be negative'
+    },
+    {
+      code: 6040
+      name: 'amountNotVested'
+      msg: 'Withdrawal exceeds the amount vested so far'
+    },
+    {
+      code: 6041
+      name: 'invalidWithdrawalLimit'
+      msg: 'A withdrawal limit needs a window, and once unlocked it can only be tightened'
+    },
+    {
+      code: 6042
+      name: 'withdrawalLimitExceeded'
+      msg: 'Withdrawal exceeds the allowance of the current window; see the logs for when it resets'
+    },
+    {
+      code: 6043
+      name: 'invalidMilestones'
+      msg: 'Milestones must ascend below 100% of the target, unlock up to 100%, and are not allowed for crowdfunds'
+    },
+    {
+      code: 6044
+      name: 'invalidPauseEnd'
+      msg: 'The automatic end of a pause must be in the future'
+    },
+    {
+      code: 6045
+      name: 'contributionStillRefundable'
+      msg: 'The crowdfund may still fail, so its contribution receipts are kept for refunds'
+    },
+    {
+      code: 6046
+      name: 'targetDecreaseUnlocks'
+      msg: 'Lowering the target this far would unlock the LockBox, which costs the early-exit penalty (pay_penalty)'
+    },
+    {
+      code: 6047
+      name: 'guardianCannotOwn'
+      msg: 'A guardian cannot become the owner of the LockBox it guards'
+    },
+    {
+      code: 6048
+      name: 'emergencyExitRestricted'
+      msg: 'An unlocked LockBox with vesting or a withdrawal limit pays out through withdraw, not an emergency withdrawal'
+    },
+    {
+      code: 6049
+      name: 'staleContribution'
+      msg: 'This contribution receipt belongs to an earlier LockBox at the same address'
+    },
+  ]
+  types: [
+    {
+      name: 'config'
+      type: {
+        kind: 'struct'
+        fields: [
+          {
+            name: 'admin'
+            type: 'pubkey'
+          },
+          {
+            name: 'params'
+            type: {
+              defined: {
+                name: 'configParams'
+              }
+            }
+          },
+          {
+            name: 'lockboxCount'
+            type: 'u64'
+          },
+          {
+            name: 'bump'
+            type: 'u8'
+          },
+        ]
+      }
+    },
+    {
+      name: 'configParams'
+      docs: ['Admin-tunable program parameters, stored on the global Config PDA']
+      type: {
+        kind: 'struct'
+        fields: [
+          {
+            name: 'treasury'
+            type: 'pubkey'
+          },
+          {
+            name: 'minPenaltyBps'
+            type: 'u16'
+          },
+          {
+            name: 'maxPenaltyBps'
+            type: 'u16'
+          },
+          {
+            name: 'minTargetAmount'
+            type: 'u64'
+          },
+          {
+            name: 'maxTargetAmount'
+            type: 'u64'
+          },
+          {
+            name: 'paused'
+            type: 'bool'
+          },
+          {
+            name: 'minInactivityPeriod'
+            type: 'i64'
+          },
+        ]
+      }
+    },
+    {
+      name: 'contribution'
+      docs: ['Receipt of everything one contributor has paid into a LockBox']
+      type: {
+        kind: 'struct'
+        fields: [
+          {
+            name: 'lockbox'
+            type: 'pubkey'
+          },
+          {
+            name: 'lockboxNonce'
+            type: 'u64'
+          },
+          {
+            name: 'contributor'
+            type: 'pubkey'
+          },
+          {
+            name: 'totalAmount'
+            type: 'u64'
+          },
+          {
+            name: 'firstContributionAt'
+            type: 'i64'
+          },
+          {
+            name: 'lastContributionAt'
+            type: 'i64'
+          },
+          {
+            name: 'count'
+            type: 'u32'
+          },
+          {
+            name: 'bump'
+            type: 'u8'
+          },
+        ]
+      }
+    },
+    {
+      name: 'deposited'
+      type: {
+        fields: [
+          {
+            name: 'lockbox'
+            type: 'pubkey'
+          },
+          {
+            name: 'owner'
+            type: 'pubkey'
+          },
+          {
+            name: 'depositor'
+            type: 'pubkey'
+          },
+          {
+            name: 'amount'
+            type: 'u64'
+          },
+          {
+            name: 'balance'
+            type: 'u64'
+          },
+          {
+            name: 'timestamp'
+            type: 'i64'
+          },
+        ]
+        kind: 'struct'
+      }
+    },
+    {
+      name: 'emergencyWithdrawn'
+      type: {
+        fields: [
+          {
+            name: 'lockbox'
+            type: 'pubkey'
+          },
+          {
+            name: 'owner'
+            type: 'pubkey'
+          },
+          {
+            name: 'recipient'
+            type: 'pubkey'
+          },
+          {
+            name: 'amount'
+            type: 'u64'
+          },
+          {
+            name: 'penalty'
+            type: 'u64'
+          },
+          {
+            name: 'timestamp'
+            type: 'i64'
+          },
+        ]
+        kind: 'struct'
+      }
+    },
+    {
+      name: 'lockBox'
+      type: {
+        kind: 'struct'
+        fields: [
+          {
+            name: 'owner'
+            type: 'pubkey'
+          },
+          {
+            name: 'creator'
+            type: 'pubkey'
+          },
+          {
+            name: 'pendingOwner'
+            type: {
+              option: 'pubkey'
+            }
+          },
+          {
+            name: 'beneficiary'
+            type: {
+              option: 'pubkey'
+            }
+          },
+          {
+            name: 'id'
+            type: 'u64'
+          },
+          {
+            name: 'nonce'
+            type: 'u64'
+          },
+          {
+            name: 'mint'
+            type: {
+              option: 'pubkey'
+            }
+          },
+          {
+            name: 'targetAmount'
+            type: 'u64'
+          },
+          {
+            name: 'highestTarget'
+            type: 'u64'
+          },
+          {
+            name: 'currentBalance'
+            type: 'u64'
+          },
+          {
+            name: 'createdAt'
+            type: 'i64'
+          },
+          {
+            name: 'targetUpdatedAt'
+            type: 'i64'
+          },
+          {
+            name: 'unlockAt'
+            type: {
+              option: 'i64'
+            }
+          },
+          {
+            name: 'unlockPolicy'
+            type: {
+              defined: {
+                name: 'unlockPolicy'
+              }
+            }
+          },
+          {
+            name: 'penaltySchedule'
+            type: {
+              defined: {
+                name: 'penaltySchedule'
+              }
+            }
+          },
+          {
+            name: 'crowdfundDeadline'
+            type: {
+              option: 'i64'
+            }
+          },
+          {
+            name: 'guardians'
+            type: {
+              vec: 'pubkey'
+            }
+          },
+          {
+            name: 'guardianThreshold'
+            type: 'u8'
+          },
+          {
+            name: 'signers'
+            type: {
+              vec: 'pubkey'
+            }
+          },
+          {
+            name: 'signerThreshold'
+            type: 'u8'
+          },
+          {
+            name: 'proposalCount'
+            type: 'u64'
+          },
+          {
+            name: 'heir'
+            type: {
+              option: 'pubkey'
+            }
+          },
+          {
+            name: 'inactivityPeriod'
+            type: 'i64'
+          },
+          {
+            name: 'lastActivityAt'
+            type: 'i64'
+          },
+          {
+            name: 'vestingDuration'
+            type: 'i64'
+          },
+          {
+            name: 'unlockedAt'
+            type: {
+              option: 'i64'
+            }
+          },
+          {
+            name: 'withdrawnSinceUnlock'
+            type: 'u64'
+          },
+          {
+            name: 'withdrawLimit'
+            type: 'u64'
+          },
+          {
+            name: 'withdrawWindow'
+            type: 'i64'
+          },
+          {
+            name: 'windowUpdatedAt'
+            type: 'i64'
+          },
+          {
+            name: 'windowUsed'
+            type: 'u64'
+          },
+          {
+            name: 'milestones'
+            type: {
+              vec: {
+                defined: {
+                  name: 'milestone'
+                }
+              }
+            }
+          },
+          {
+            name: 'milestonesReached'
+            type: 'u8'
+          },
+          {
+            name: 'milestoneAllowance'
+            type: 'u64'
+          },
+          {
+            name: 'brokenAt'
+            type: {
+              option: 'i64'
+            }
+          },
+          {
+            name: 'emergencyWithdrawn'
+            type: 'u64'
+          },
+          {
+            name: 'emergencyPenalty'
+            type: 'u64'
+          },
+          {
+            name: 'statusBeforePause'
+            type: {
+              defined: {
+                name: 'lockBoxStatus'
+              }
+            }
+          },
+          {
+            name: 'pausedUntil'
+            type: {
+              option: 'i64'
+            }
+          },
+          {
+            name: 'status'
+            type: {
+              defined: {
+                name: 'lockBoxStatus'
+              }
+            }
+          },
+          {
+            name: 'bump'
+            type: 'u8'
+          },
+        ]
+      }
+    },
+    {
+      name: 'lockBoxClosed'
+      type: {
+        fields: [
+          {
+            name: 'lockbox'
+            type: 'pubkey'
+          },
+          {
+            name: 'owner'
+            type: 'pubkey'
+          },
+          {
+            name: 'timestamp'
+            type: 'i64'
+          },
+        ]
+        kind: 'struct'
+      }
+    },
+    {
+      name: 'lockBoxCreated'
+      type: {
+        fields: [
+          {
+            name: 'lockbox'
+            type: 'pubkey'
+          },
+          {
+            name: 'owner'
+            type: 'pubkey'
+          },
+          {
+            name: 'mint'
+            type: {
+              option: 'pubkey'
+            }
+          },
+          {
+            name: 'targetAmount'
+            type: 'u64'
+          },
+          {
+            name: 'unlockAt'
+            type: {
+              option: 'i64'
+            }
+          },
+          {
+            name: 'timestamp'
+            type: 'i64'
+          },
+        ]
+        kind: 'struct'
+      }
+    },
+    {
+      name: 'lockBoxParams'
+      docs: ['Creation parameters shared by SOL and token LockBoxes']
+      type: {
+        kind: 'struct'
+        fields: [
+          {
+            name: 'targetAmount'
+            type: 'u64'
+          },
+          {
+            name: 'unlockAt'
+            type: {
+              option: 'i64'
+            }
+          },
+          {
+            name: 'unlockPolicy'
+            type: {
+              defined: {
+                name: 'unlockPolicy'
+              }
+            }
+          },
+          {
+            name: 'penaltySchedule'
+            type: {
+              defined: {
+                name: 'penaltySchedule'
+              }
+            }
+          },
+          {
+            name: 'crowdfundDeadline'
+            type: {
+              option: 'i64'
+            }
+          },
+          {
+            name: 'beneficiary'
+            type: {
+              option: 'pubkey'
+            }
+          },
+          {
+            name: 'vestingDuration'
+            type: 'i64'
+          },
+          {
+            name: 'milestones'
+            type: {
+              vec: {
+                defined: {
+                  name: 'milestone'
+                }
+              }
+            }
+          },
+        ]
+      }
+    },
+    {
+      name: 'lockBoxPaused'
+      type: {
+        fields: [
+          {
+            name: 'lockbox'
+            type: 'pubkey'
+          },
+          {
+            name: 'owner'
+            type: 'pubkey'
+          },
+          {
+            name: 'pausedUntil'
+            type: {
+              option: 'i64'
+            }
+          },
+          {
+            name: 'timestamp'
+            type: 'i64'
+          },
+        ]
+        kind: 'struct'
+      }
+    },
+    {
+      name: 'lockBoxResumed'
+      type: {
+        fields: [
+          {
+            name: 'lockbox'
+            type: 'pubkey'
+          },
+          {
+            name: 'owner'
+            type: 'pubkey'
+          },
+          {
+            name: 'timestamp'
+            type: 'i64'
+          },
+        ]
+        kind: 'struct'
+      }
+    },
+    {
+      docs: ['Which setting a `SettingsChanged` event reports; the new value is on the LockBox']
+      name: 'lockBoxSetting'
+      type: {
+        kind: 'enum'
+        variants: [
+          {
+            name: 'pendingOwner'
+          },
+          {
+            name: 'beneficiary'
+          },
+          {
+            name: 'guardians'
+          },
+          {
+            name: 'multisig'
+          },
+          {
+            name: 'heir'
+          },
+          {
+            name: 'withdrawalLimit'
+          },
+          {
+            name: 'target'
+          },
+        ]
+      }
     },
-  ]
-  accounts: [
     {
-      name: 'lockBox'
-      discriminator: [146, 255, 195, 118, 40, 186, 40, 119]
+      name: 'lockBoxStatus'
+      docs: ['Lifecycle of a LockBox. Every instruction validates its transition.']
+      type: {
+        kind: 'enum'
+        variants: [
+          {
+            name: 'active'
+          },
+          {
+            name: 'unlocked'
+          },
+          {
+            name: 'paused'
+          },
+          {
+            name: 'broken'
+          },
+          {
+            name: 'completed'
+          },
+          {
+            name: 'closed'
+          },
+        ]
+      }
     },
-  ]
-  errors: [
     {
-      code: 6000
-      name: 'invalidTargetAmount'
-      msg: 'Target amount must be greater than zero'
+      name: 'milestone'
+      docs: [
+        'Partial unlock step: once the balance reaches `threshold_bps` of the target,',
+        '`unlock_bps` of the balance at that moment can be withdrawn before the full unlock.',
+      ]
+      type: {
+        kind: 'struct'
+        fields: [
+          {
+            name: 'thresholdBps'
+            type: 'u16'
+          },
+          {
+            name: 'unlockBps'
+            type: 'u16'
+          },
+        ]
+      }
     },
     {
-      code: 6001
-      name: 'invalidDepositAmount'
-      msg: 'Deposit amount must be greater than zero'
+      name: 'ownershipTransferred'
+      type: {
+        fields: [
+          {
+            name: 'lockbox'
+            type: 'pubkey'
+          },
+          {
+            name: 'owner'
+            type: 'pubkey'
+          },
+          {
+            name: 'previousOwner'
+            type: 'pubkey'
+          },
+          {
+            name: 'inherited'
+            type: 'bool'
+          },
+          {
+            name: 'timestamp'
+            type: 'i64'
+          },
+        ]
+        kind: 'struct'
+      }
     },
     {
-      code: 6002
-      name: 'targetNotReached'
-      msg: 'Target not reached yet. Current balance is below the target amount.'
+      name: 'penaltySchedule'
+      docs: [
+        'Early-exit penalty that decays linearly with time elapsed since creation',
+        'and with progress toward the target.',
+      ]
+      type: {
+        kind: 'struct'
+        fields: [
+          {
+            name: 'maxBps'
+            type: 'u16'
+          },
+          {
+            name: 'decayDuration'
+            type: 'i64'
+          },
+        ]
+      }
     },
     {
-      code: 6003
-      name: 'vaultInactive'
-      msg: 'This vault has been deactivated and is no longer accessible'
+      name: 'proposal'
+      docs: ['Pending multisig action, closed back to the proposer once executed']
+      type: {
+        kind: 'struct'
+        fields: [
+          {
+            name: 'lockbox'
+            type: 'pubkey'
+          },
+          {
+            name: 'id'
+            type: 'u64'
+          },
+          {
+            name: 'proposer'
+            type: 'pubkey'
+          },
+          {
+            name: 'action'
+            type: {
+              defined: {
+                name: 'proposalAction'
+              }
+            }
+          },
+          {
+            name: 'approvals'
+            type: {
+              vec: 'pubkey'
+            }
+          },
+          {
+            name: 'createdAt'
+            type: 'i64'
+          },
+          {
+            name: 'bump'
+            type: 'u8'
+          },
+        ]
+      }
     },
     {
-      code: 6004
-      name: 'unauthorized'
-      msg: 'Unauthorized: Only the owner can perform this action'
+      name: 'proposalAction'
+      docs: ['What a multisig proposal does once enough signers approved it']
+      type: {
+        kind: 'enum'
+        variants: [
+          {
+            name: 'withdraw'
+            fields: [
+              {
+                name: 'amount'
+                type: 'u64'
+              },
+            ]
+          },
+          {
+            name: 'close'
+          },
+          {
+            name: 'pause'
+            fields: [
+              {
+                name: 'pausedUntil'
+                type: {
+                  option: 'i64'
+                }
+              },
+            ]
+          },
+          {
+            name: 'resume'
+          },
+        ]
+      }
     },
     {
-      code: 6005
-      name: 'insufficientBalance'
-      msg: 'Insufficient balance in vault for withdrawal'
+      name: 'proposalApproved'
+      type: {
+        fields: [
+          {
+            name: 'lockbox'
+            type: 'pubkey'
+          },
+          {
+            name: 'owner'
+            type: 'pubkey'
+          },
+          {
+            name: 'proposal'
+            type: 'pubkey'
+          },
+          {
+            name: 'proposalId'
+            type: 'u64'
+          },
+          {
+            name: 'approver'
+            type: 'pubkey'
+          },
+          {
+            name: 'approvals'
+            type: 'u8'
+          },
+          {
+            name: 'timestamp'
+            type: 'i64'
+          },
+        ]
+        kind: 'struct'
+      }
     },
-  ]
-  types: [
     {
-      name: 'lockBox'
+      name: 'proposalCreated'
+      type: {
+        fields: [
+          {
+            name: 'lockbox'
+            type: 'pubkey'
+          },
+          {
+            name: 'owner'
+            type: 'pubkey'
+          },
+          {
+            name: 'proposal'
+            type: 'pubkey'
+          },
+          {
+            name: 'proposalId'
+            type: 'u64'
+          },
+          {
+            name: 'proposer'
+            type: 'pubkey'
+          },
+          {
+            name: 'action'
+            type: {
+              defined: {
+                name: 'proposalAction'
+              }
+            }
+          },
+          {
+            name: 'timestamp'
+            type: 'i64'
+          },
+        ]
+        kind: 'struct'
+      }
+    },
+    {
+      name: 'settingsChanged'
       type: {
+        fields: [
+          {
+            name: 'lockbox'
+            type: 'pubkey'
+          },
+          {
+            name: 'owner'
+            type: 'pubkey'
+          },
+          {
+            name: 'setting'
+            type: {
+              defined: {
+                name: 'lockBoxSetting'
+              }
+            }
+          },
+          {
+            name: 'timestamp'
+            type: 'i64'
+          },
+        ]
         kind: 'struct'
+      }
+    },
+    {
+      name: 'targetReached'
+      type: {
         fields: [
+          {
+            name: 'lockbox'
+            type: 'pubkey'
+          },
           {
             name: 'owner'
             type: 'pubkey'
           },
           {
-            name: 'targetAmount'
+            name: 'balance'
             type: 'u64'
           },
           {
-            name: 'currentBalance'
+            name: 'targetAmount'
             type: 'u64'
           },
           {
-            name: 'createdAt'
+            name: 'timestamp'
             type: 'i64'
           },
+        ]
+        kind: 'struct'
+      }
+    },
+    {
+      name: 'unlockPolicy'
+      docs: ['Which conditions must hold before `withdraw` is allowed']
+      type: {
+        kind: 'enum'
+        variants: [
           {
-            name: 'hasReachedTarget'
-            type: 'bool'
+            name: 'targetOnly'
           },
           {
-            name: 'bump'
-            type: 'u8'
+            name: 'timeOnly'
+          },
+          {
+            name: 'targetOrTime'
+          },
+          {
+            name: 'targetAndTime'
+          },
+        ]
+      }
+    },
+    {
+      name: 'withdrawn'
+      type: {
+        fields: [
+          {
+            name: 'lockbox'
+            type: 'pubkey'
+          },
+          {
+            name: 'owner'
+            type: 'pubkey'
+          },
+          {
+            name: 'recipient'
+            type: 'pubkey'
+          },
+          {
+            name: 'amount'
+            type: 'u64'
+          },
+          {
+            name: 'balance'
+            type: 'u64'
+          },
+          {
+            name: 'timestamp'
+            type: 'i64'
           },
         ]
+        kind: 'struct'
       }
     },
   ]
@@ -8,9 +8,20 @@ import { BN } from '@coral-xyz/anchor'
 import { toast } from 'sonner'
 import { getLockBoxProgram, LOCK_BOX_PROGRAM_ID } from '@/anchor/lock_box_exports'
 
-// Helper function to derive LockBox PDA
-function getLockBoxPda(owner: PublicKey): [PublicKey, number] {
-  return PublicKey.findProgramAddressSync([Buffer.from('lockbox'), owner.toBuffer()], LOCK_BOX_PROGRAM_ID)
+// The dashboard manages each wallet's first LockBox
+const DEFAULT_LOCKBOX_ID = new BN(0)
+
+// Helper function to derive LockBox PDA. LockBoxes are keyed by their creator and id.
+function getLockBoxPda(creator: PublicKey, lockboxId: BN = DEFAULT_LOCKBOX_ID): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from('lockbox'), creator.toBuffer(), lockboxId.toArrayLike(Buffer, 'le', 8)],
+    LOCK_BOX_PROGRAM_ID,
+  )
+}
+
+// Helper function to derive the global Config PDA
+function getConfigPda(): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync([Buffer.from('config')], LOCK_BOX_PROGRAM_ID)
 }
 
 // Hook to get Anchor provider
@@ -68,7 +79,7 @@ export function useGetLockBox({ address }: { address: PublicKey | null | undefin
             targetAmount: lockboxAccount.targetAmount,
             currentBalance: lockboxAccount.currentBalance,
             createdAt: lockboxAccount.createdAt,
-            hasReachedTarget: lockboxAccount.hasReachedTarget,
+            hasReachedTarget: lockboxAccount.currentBalance.gte(lockboxAccount.targetAmount),
             bump: lockboxAccount.bump,
           },
           address: lockboxPda,
@@ -96,9 +107,21 @@ export function useInitializeLockBox({ address }: { address: PublicKey | null |
       }
 
       const program = getLockBoxProgram(provider)
+      const [configPda] = getConfigPda()
+      const config = await program.account.config.fetch(configPda)
 
+      // A plain savings goal: unlocks once the target is reached, at the lowest allowed penalty
       const tx = await program.methods
-        .initializeLockbox(new BN(input.targetAmount))
+        .initializeLockbox(DEFAULT_LOCKBOX_ID, {
+          targetAmount: new BN(input.targetAmount),
+          unlockAt: null,
+          unlockPolicy: { targetOnly: {} },
+          penaltySchedule: { maxBps: config.params.minPenaltyBps, decayDuration: new BN(0) },
+          crowdfundDeadline: null,
+          beneficiary: null,
+          vestingDuration: new BN(0),
+          milestones: [],
+        })
         .accounts({
           owner: address,
         })
@@ -136,10 +159,12 @@ export function useDeposit({ address }: { address: PublicKey | null | undefined
       }
 
       const program = getLockBoxProgram(provider)
+      const [lockboxPda] = getLockBoxPda(address)
 
       const tx = await program.methods
         .deposit(new BN(input.amount))
         .accounts({
+          lockbox: lockboxPda,
           owner: address,
         })
         .rpc()
@@ -182,10 +207,12 @@ export function useWithdraw({ address }: { address: PublicKey | null | undefined
       }
 
       const program = getLockBoxProgram(provider)
+      const [lockboxPda] = getLockBoxPda(address)
 
       const tx = await program.methods
         .withdraw(new BN(input.amount))
         .accounts({
+          lockbox: lockboxPda,
           owner: address,
         })
         .rpc()
@@ -228,11 +255,17 @@ export function useEmergencyWithdraw({ address }: { address: PublicKey | null |
       }
 
       const program = getLockBoxProgram(provider)
+      const [lockboxPda] = getLockBoxPda(address)
+      const [configPda] = getConfigPda()
+      const config = await program.account.config.fetch(configPda)
 
+      // Don't keep the broken LockBox as a record, so its rent is returned as well
       const tx = await program.methods
-        .emergencyWithdraw()
+        .emergencyWithdraw(false)
         .accounts({
+          lockbox: lockboxPda,
           owner: address,
+          treasury: config.params.treasury,
         })
         .rpc()
 
@@ -240,7 +273,7 @@ export function useEmergencyWithdraw({ address }: { address: PublicKey | null |
     },
     onSuccess: async (tx) => {
       toast.success('Emergency Withdrawal Completed', {
-        description: `All funds have been withdrawn and your Lock Box has been closed. Transaction: ${tx.slice(0, 8)}...`,
+        description: `Your funds, minus any early-exit penalty, have been withdrawn and your Lock Box has been closed. Transaction: ${tx.slice(0, 8)}...`,
       })
       await Promise.all([
         client.invalidateQueries({
@@ -273,10 +306,12 @@ export function useCloseLockBox({ address }: { address: PublicKey | null | undef
       }
 
       const program = getLockBoxProgram(provider)
+      const [lockboxPda] = getLockBoxPda(address)
 
       const tx = await program.methods
         .closeLockbox()
         .accounts({
+          lockbox: lockboxPda,
           owner: address,
         })
         .rpc()